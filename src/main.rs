@@ -1,7 +1,14 @@
 mod workflow;
 mod random;
+mod region;
+mod query;
+mod pinyin;
+mod validate;
 
 use clap::Parser;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use query::Query;
 use random::RandomKind;
 
 pub static AUTHOR_WEBSITE: &str = "hongdenglv.com";
@@ -12,46 +19,80 @@ struct Args {
     ///生成随机数据的类型
     #[arg(short, long)]
     kind: String,
-    ///生成随机数据的长度 默认为0 不限制
+    ///生成随机数据的长度 默认为0 使用各类型的默认长度
     #[arg(short, long, default_value_t = 0)]
-    length: i8,
+    length: u8,
     ///生成随机数据的个数
     #[arg(short, long, default_value_t = 9)]
     number: u8,
-}
-
-impl Args {
-    pub fn new(kind: String, length: i8, number: u8) -> Args {
-        Args {
-            kind,
-            length,
-            number,
-        }
-    }
+    ///随机种子 相同的种子和查询生成相同的数据, 便于复现
+    #[arg(short, long)]
+    seed: Option<u64>,
+    ///附加参数 例如 `number 1..100 x20` 中 kind 之后的部分, 也可以直接把整个查询传给 --kind
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    options: Vec<String>,
 }
 
 pub struct GenerateRandom {
     kind: RandomKind,
     value: String,
+    subtitle: Option<String>,
+    ///按住修饰键时的备选结果 (说明, 值), 依次对应 alt、cmd、ctrl、shift、fn
+    alternatives: Vec<(String, String)>,
 }
 impl GenerateRandom {
     pub fn new(kind: RandomKind, value: String) -> GenerateRandom {
         GenerateRandom {
             kind,
             value,
+            subtitle: None,
+            alternatives: Vec::new(),
         }
     }
+
+    /// 替换默认以 kind 名称作为的副标题, 例如银行卡显示发卡行
+    pub fn with_subtitle(mut self, subtitle: String) -> GenerateRandom {
+        self.subtitle = Some(subtitle);
+        self
+    }
+
+    /// 增加一个按住修饰键时复制的备选结果, 例如地址对应的邮政编码
+    pub fn with_alternative(mut self, label: String, value: String) -> GenerateRandom {
+        self.alternatives.push((label, value));
+        self
+    }
 }
 
 fn main() {
     let args = Args::parse();
-    let args = Args::new(args.kind, -1, 9);
-    let random_kind = RandomKind::get_random_by_name(args.kind);
+    let (random_kind, mut options) = match Query::parse(&args.kind, &args.options) {
+        Query::Generate { kind, options } => (kind, options),
+        Query::Choose { kinds, rest } => {
+            println!("{}", workflow::Workflows::choose(kinds, &rest).to_json());
+            return;
+        }
+        Query::NotFound { name, suggestions, rest } => {
+            println!("{}", workflow::Workflows::not_found(&name, suggestions, &rest).to_json());
+            return;
+        }
+        Query::Validate { name, kind, value } => {
+            println!("{}", workflow::Workflows::validate(&name, kind, &value).to_json());
+            return;
+        }
+    };
+    // 查询中的长度和个数优先于命令行参数
+    if options.length.is_none() && args.length > 0 {
+        options.length = Some(args.length as usize);
+    }
+    let number = options.count.unwrap_or(args.number as usize);
     // println!("current random kind is {:?} !", random_kind);
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
     let mut random_value_list = Vec::new();
-    for _ in 0..args.number {
-        let random_value = random::random_value(random_kind, args.length);
-        random_value_list.push(GenerateRandom::new(random_kind, random_value));
+    for _ in 0..number {
+        random_value_list.push(random::random_value(random_kind, &options, &mut rng));
     }
     let workflows = workflow::Workflows::new(random_value_list);
     println!("{}", workflows.to_json());
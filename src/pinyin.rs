@@ -0,0 +1,165 @@
+/// 姓名的拼音, 按音节保存带声调的拼音
+pub struct NamePinyin {
+    surname: Vec<&'static str>,
+    given: Vec<&'static str>,
+}
+
+impl NamePinyin {
+    /// 姓氏优先按姓氏读音查找 (如 单 Shan, 曾 Zeng), 有字不在读音表中时返回 None
+    pub fn new(surname: &str, given: &str) -> Option<NamePinyin> {
+        let surname = match SURNAME_PINYIN.iter().find(|(s, _)| *s == surname) {
+            Some((_, syllables)) => syllables.to_vec(),
+            None => syllables(surname)?,
+        };
+        Some(NamePinyin { surname, given: syllables(given)? })
+    }
+
+    /// 带声调, 例如 Zhāng Sān
+    pub fn with_tone(&self) -> String {
+        format!("{} {}", capitalize(&join_with_apostrophe(&self.surname)), capitalize(&join_with_apostrophe(&self.given)))
+    }
+
+    /// 首字母大写, 例如 Zhang San
+    pub fn capitalized(&self) -> String {
+        format!("{} {}", capitalize(&strip_tone(&self.surname)), capitalize(&strip_tone(&self.given)))
+    }
+
+    /// 姓氏, 全小写无声调
+    pub fn surname(&self) -> String {
+        strip_tone(&self.surname)
+    }
+
+    /// 名, 全小写无声调
+    pub fn given_name(&self) -> String {
+        strip_tone(&self.given)
+    }
+
+    /// 全小写连写, 适合作为用户名, 例如 zhangsan
+    pub fn compact(&self) -> String {
+        format!("{}{}", self.surname(), self.given_name())
+    }
+
+    /// 姓氏全大写, 例如 ZHANG San
+    pub fn surname_upper(&self) -> String {
+        format!("{} {}", strip_tone(&self.surname).to_uppercase(), capitalize(&strip_tone(&self.given)))
+    }
+}
+
+fn syllables(text: &str) -> Option<Vec<&'static str>> {
+    text.chars()
+        .map(|c| PINYIN.iter().find(|(h, _)| *h == c).map(|(_, p)| *p))
+        .collect()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// a/o/e 开头的音节前加隔音符号, 例如 jiā'ān
+fn join_with_apostrophe(syllables: &[&str]) -> String {
+    let mut text = String::new();
+    for (i, syllable) in syllables.iter().enumerate() {
+        if i > 0 && strip_tone(&[*syllable]).starts_with(['a', 'o', 'e']) {
+            text.push('\'');
+        }
+        text.push_str(syllable);
+    }
+    text
+}
+
+/// 去掉声调并连写, ü 按护照拼写规则写作 yu, 例如 lǚ -> lyu
+fn strip_tone(syllables: &[&str]) -> String {
+    syllables.concat().chars()
+        .map(|c| match c {
+            'ā' | 'á' | 'ǎ' | 'à' => "a".to_string(),
+            'ē' | 'é' | 'ě' | 'è' => "e".to_string(),
+            'ī' | 'í' | 'ǐ' | 'ì' => "i".to_string(),
+            'ō' | 'ó' | 'ǒ' | 'ò' => "o".to_string(),
+            'ū' | 'ú' | 'ǔ' | 'ù' => "u".to_string(),
+            'ü' | 'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' => "yu".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// 多音字作姓氏时的读音, 复姓按音节拆分
+const SURNAME_PINYIN: &[(&str, &[&str])] = &[
+    ("单", &["shàn"]), ("曾", &["zēng"]), ("解", &["xiè"]), ("仇", &["qiú"]), ("查", &["zhā"]), ("盖", &["gě"]),
+    ("乐", &["yuè"]), ("华", &["huà"]), ("纪", &["jǐ"]), ("曲", &["qū"]), ("宓", &["fú"]), ("薄", &["bó"]),
+    ("能", &["nài"]), ("燕", &["yān"]), ("都", &["dū"]), ("蔚", &["yù"]), ("那", &["nā"]), ("乜", &["niè"]),
+    ("於", &["yū"]), ("缪", &["miào"]), ("翟", &["zhái"]), ("訾", &["zī"]), ("哈", &["hǎ"]), ("万俟", &["mò", "qí"]),
+    ("单于", &["chán", "yú"]), ("尉迟", &["yù", "chí"]), ("澹台", &["tán", "tái"]), ("长孙", &["zhǎng", "sūn"]), ("令狐", &["líng", "hú"]), ("子车", &["zǐ", "jū"]),
+    ("夹谷", &["jiá", "gǔ"]), ("宰父", &["zǎi", "fǔ"]), ("乐正", &["yuè", "zhèng"]),
+];
+
+/// 姓名用字的拼音, 覆盖 random.rs 中全部姓氏和名字用字, 多音字取名字中的常用读音
+const PINYIN: &[(char, &str)] = &[
+    ('赵', "zhào"), ('钱', "qián"), ('孙', "sūn"), ('李', "lǐ"), ('周', "zhōu"), ('吴', "wú"), ('郑', "zhèng"), ('王', "wáng"), ('冯', "féng"), ('陈', "chén"),
+    ('褚', "chǔ"), ('卫', "wèi"), ('蒋', "jiǎng"), ('沈', "shěn"), ('韩', "hán"), ('杨', "yáng"), ('朱', "zhū"), ('秦', "qín"), ('尤', "yóu"), ('许', "xǔ"),
+    ('何', "hé"), ('吕', "lǚ"), ('施', "shī"), ('张', "zhāng"), ('孔', "kǒng"), ('曹', "cáo"), ('严', "yán"), ('华', "huá"), ('金', "jīn"), ('魏', "wèi"),
+    ('陶', "táo"), ('姜', "jiāng"), ('戚', "qī"), ('谢', "xiè"), ('邹', "zōu"), ('喻', "yù"), ('柏', "bǎi"), ('水', "shuǐ"), ('窦', "dòu"), ('章', "zhāng"),
+    ('云', "yún"), ('苏', "sū"), ('潘', "pān"), ('葛', "gě"), ('奚', "xī"), ('范', "fàn"), ('彭', "péng"), ('郎', "láng"), ('鲁', "lǔ"), ('韦', "wéi"),
+    ('昌', "chāng"), ('马', "mǎ"), ('苗', "miáo"), ('凤', "fèng"), ('花', "huā"), ('方', "fāng"), ('俞', "yú"), ('任', "rén"), ('袁', "yuán"), ('柳', "liǔ"),
+    ('酆', "fēng"), ('鲍', "bào"), ('史', "shǐ"), ('唐', "táng"), ('费', "fèi"), ('廉', "lián"), ('岑', "cén"), ('薛', "xuē"), ('雷', "léi"), ('贺', "hè"),
+    ('倪', "ní"), ('汤', "tāng"), ('滕', "téng"), ('殷', "yīn"), ('罗', "luó"), ('毕', "bì"), ('郝', "hǎo"), ('邬', "wū"), ('安', "ān"), ('常', "cháng"),
+    ('乐', "lè"), ('于', "yú"), ('时', "shí"), ('傅', "fù"), ('皮', "pí"), ('卞', "biàn"), ('齐', "qí"), ('康', "kāng"), ('伍', "wǔ"), ('余', "yú"),
+    ('元', "yuán"), ('卜', "bǔ"), ('顾', "gù"), ('孟', "mèng"), ('平', "píng"), ('黄', "huáng"), ('和', "hé"), ('穆', "mù"), ('萧', "xiāo"), ('尹', "yǐn"),
+    ('姚', "yáo"), ('邵', "shào"), ('湛', "zhàn"), ('汪', "wāng"), ('祁', "qí"), ('毛', "máo"), ('禹', "yǔ"), ('狄', "dí"), ('米', "mǐ"), ('贝', "bèi"),
+    ('明', "míng"), ('臧', "zāng"), ('计', "jì"), ('伏', "fú"), ('成', "chéng"), ('戴', "dài"), ('谈', "tán"), ('宋', "sòng"), ('茅', "máo"), ('庞', "páng"),
+    ('熊', "xióng"), ('纪', "jì"), ('舒', "shū"), ('屈', "qū"), ('项', "xiàng"), ('祝', "zhù"), ('董', "dǒng"), ('梁', "liáng"), ('杜', "dù"), ('阮', "ruǎn"),
+    ('蓝', "lán"), ('闵', "mǐn"), ('席', "xí"), ('季', "jì"), ('麻', "má"), ('强', "qiáng"), ('贾', "jiǎ"), ('路', "lù"), ('娄', "lóu"), ('危', "wēi"),
+    ('江', "jiāng"), ('童', "tóng"), ('颜', "yán"), ('郭', "guō"), ('梅', "méi"), ('盛', "shèng"), ('林', "lín"), ('刁', "diāo"), ('钟', "zhōng"), ('徐', "xú"),
+    ('邱', "qiū"), ('骆', "luò"), ('高', "gāo"), ('夏', "xià"), ('蔡', "cài"), ('田', "tián"), ('樊', "fán"), ('胡', "hú"), ('凌', "líng"), ('霍', "huò"),
+    ('虞', "yú"), ('万', "wàn"), ('支', "zhī"), ('柯', "kē"), ('昝', "zǎn"), ('管', "guǎn"), ('卢', "lú"), ('莫', "mò"), ('经', "jīng"), ('房', "fáng"),
+    ('裘', "qiú"), ('缪', "miù"), ('干', "gān"), ('解', "jiě"), ('应', "yīng"), ('宗', "zōng"), ('丁', "dīng"), ('宣', "xuān"), ('贲', "bēn"), ('邓', "dèng"),
+    ('郁', "yù"), ('单', "dān"), ('杭', "háng"), ('洪', "hóng"), ('包', "bāo"), ('诸', "zhū"), ('左', "zuǒ"), ('石', "shí"), ('崔', "cuī"), ('吉', "jí"),
+    ('钮', "niǔ"), ('龚', "gōng"), ('程', "chéng"), ('嵇', "jī"), ('邢', "xíng"), ('滑', "huá"), ('裴', "péi"), ('陆', "lù"), ('荣', "róng"), ('翁', "wēng"),
+    ('荀', "xún"), ('羊', "yáng"), ('於', "yú"), ('惠', "huì"), ('甄', "zhēn"), ('曲', "qǔ"), ('家', "jiā"), ('封', "fēng"), ('芮', "ruì"), ('羿', "yì"),
+    ('储', "chǔ"), ('靳', "jìn"), ('汲', "jí"), ('邴', "bǐng"), ('糜', "mí"), ('松', "sōng"), ('井', "jǐng"), ('段', "duàn"), ('富', "fù"), ('巫', "wū"),
+    ('乌', "wū"), ('焦', "jiāo"), ('巴', "bā"), ('弓', "gōng"), ('牧', "mù"), ('隗', "wěi"), ('山', "shān"), ('谷', "gǔ"), ('车', "chē"), ('侯', "hóu"),
+    ('宓', "mì"), ('蓬', "péng"), ('全', "quán"), ('郗', "xī"), ('班', "bān"), ('仰', "yǎng"), ('秋', "qiū"), ('仲', "zhòng"), ('伊', "yī"), ('宫', "gōng"),
+    ('宁', "níng"), ('仇', "chóu"), ('栾', "luán"), ('暴', "bào"), ('甘', "gān"), ('钭', "tǒu"), ('厉', "lì"), ('戎', "róng"), ('祖', "zǔ"), ('武', "wǔ"),
+    ('符', "fú"), ('刘', "liú"), ('景', "jǐng"), ('詹', "zhān"), ('束', "shù"), ('龙', "lóng"), ('叶', "yè"), ('幸', "xìng"), ('司', "sī"), ('韶', "sháo"),
+    ('郜', "gào"), ('黎', "lí"), ('蓟', "jì"), ('薄', "báo"), ('印', "yìn"), ('宿', "sù"), ('白', "bái"), ('怀', "huái"), ('蒲', "pú"), ('邰', "tái"),
+    ('从', "cóng"), ('鄂', "è"), ('索', "suǒ"), ('咸', "xián"), ('籍', "jí"), ('赖', "lài"), ('卓', "zhuó"), ('蔺', "lìn"), ('屠', "tú"), ('蒙', "méng"),
+    ('池', "chí"), ('乔', "qiáo"), ('阴', "yīn"), ('鬱', "yù"), ('胥', "xū"), ('能', "néng"), ('苍', "cāng"), ('双', "shuāng"), ('闻', "wén"), ('莘', "shēn"),
+    ('党', "dǎng"), ('翟', "dí"), ('谭', "tán"), ('贡', "gòng"), ('劳', "láo"), ('逄', "páng"), ('姬', "jī"), ('申', "shēn"), ('扶', "fú"), ('堵', "dǔ"),
+    ('冉', "rǎn"), ('宰', "zǎi"), ('郦', "lì"), ('雍', "yōng"), ('卻', "què"), ('璩', "qú"), ('桑', "sāng"), ('桂', "guì"), ('濮', "pú"), ('牛', "niú"),
+    ('寿', "shòu"), ('通', "tōng"), ('边', "biān"), ('扈', "hù"), ('燕', "yàn"), ('冀', "jì"), ('郏', "jiá"), ('浦', "pǔ"), ('尚', "shàng"), ('农', "nóng"),
+    ('温', "wēn"), ('别', "bié"), ('庄', "zhuāng"), ('晏', "yàn"), ('柴', "chái"), ('瞿', "qú"), ('阎', "yán"), ('充', "chōng"), ('慕', "mù"), ('连', "lián"),
+    ('茹', "rú"), ('习', "xí"), ('宦', "huàn"), ('艾', "ài"), ('鱼', "yú"), ('容', "róng"), ('向', "xiàng"), ('古', "gǔ"), ('易', "yì"), ('慎', "shèn"),
+    ('戈', "gē"), ('廖', "liào"), ('庾', "yǔ"), ('终', "zhōng"), ('暨', "jì"), ('居', "jū"), ('衡', "héng"), ('步', "bù"), ('都', "dōu"), ('耿', "gěng"),
+    ('满', "mǎn"), ('弘', "hóng"), ('匡', "kuāng"), ('国', "guó"), ('文', "wén"), ('寇', "kòu"), ('广', "guǎng"), ('禄', "lù"), ('阙', "què"), ('东', "dōng"),
+    ('欧', "ōu"), ('殳', "shū"), ('沃', "wò"), ('利', "lì"), ('蔚', "wèi"), ('越', "yuè"), ('夔', "kuí"), ('隆', "lóng"), ('师', "shī"), ('巩', "gǒng"),
+    ('厍', "shè"), ('聂', "niè"), ('晁', "cháo"), ('勾', "gōu"), ('敖', "áo"), ('融', "róng"), ('冷', "lěng"), ('訾', "zǐ"), ('辛', "xīn"), ('阚', "kàn"),
+    ('那', "nà"), ('简', "jiǎn"), ('饶', "ráo"), ('空', "kōng"), ('曾', "céng"), ('毋', "wú"), ('沙', "shā"), ('乜', "miē"), ('养', "yǎng"), ('鞠', "jū"),
+    ('须', "xū"), ('丰', "fēng"), ('巢', "cháo"), ('关', "guān"), ('蒯', "kuǎi"), ('相', "xiāng"), ('查', "chá"), ('后', "hòu"), ('荆', "jīng"), ('红', "hóng"),
+    ('游', "yóu"), ('竺', "zhú"), ('权', "quán"), ('逯', "lù"), ('盖', "gài"), ('益', "yì"), ('桓', "huán"), ('公', "gōng"), ('仉', "zhǎng"), ('督', "dū"),
+    ('晋', "jìn"), ('楚', "chǔ"), ('闫', "yán"), ('法', "fǎ"), ('汝', "rǔ"), ('鄢', "yān"), ('涂', "tú"), ('钦', "qīn"), ('岳', "yuè"), ('帅', "shuài"),
+    ('缑', "gōu"), ('亢', "kàng"), ('况', "kuàng"), ('郈', "hòu"), ('佘', "shé"), ('佴', "nài"), ('墨', "mò"), ('哈', "hā"), ('谯', "qiáo"), ('笪', "dá"),
+    ('年', "nián"), ('爱', "ài"), ('阳', "yáng"), ('佟', "tóng"), ('言', "yán"), ('福', "fú"), ('肖', "xiāo"), ('上', "shàng"), ('官', "guān"), ('徒', "tú"),
+    ('令', "lìng"), ('狐', "hú"), ('皇', "huáng"), ('甫', "fǔ"), ('尉', "wèi"), ('迟', "chí"), ('端', "duān"), ('木', "mù"), ('俟', "sì"), ('人', "rén"),
+    ('赫', "hè"), ('澹', "dàn"), ('台', "tái"), ('冶', "yě"), ('政', "zhèng"), ('淳', "chún"), ('太', "tài"), ('叔', "shū"), ('轩', "xuān"), ('辕', "yuán"),
+    ('离', "lí"), ('宇', "yǔ"), ('长', "cháng"), ('鲜', "xiān"), ('闾', "lǘ"), ('丘', "qiū"), ('亓', "qí"), ('子', "zǐ"), ('颛', "zhuān"), ('西', "xī"),
+    ('漆', "qī"), ('雕', "diāo"), ('正', "zhèng"), ('壤', "rǎng"), ('驷', "sì"), ('良', "liáng"), ('拓', "tuò"), ('跋', "bá"), ('夹', "jiā"), ('父', "fù"),
+    ('百', "bǎi"), ('里', "lǐ"), ('南', "nán"), ('门', "mén"), ('呼', "hū"), ('延', "yán"), ('归', "guī"), ('海', "hǎi"), ('舌', "shé"), ('微', "wēi"),
+    ('生', "shēng"), ('商', "shāng"), ('牟', "móu"), ('伯', "bó"), ('赏', "shǎng"), ('有', "yǒu"), ('琴', "qín"), ('第', "dì"), ('五', "wǔ"), ('伟', "wěi"),
+    ('磊', "lěi"), ('军', "jūn"), ('勇', "yǒng"), ('杰', "jié"), ('涛', "tāo"), ('斌', "bīn"), ('超', "chāo"), ('刚', "gāng"), ('辉', "huī"), ('鹏', "péng"),
+    ('飞', "fēi"), ('鑫', "xīn"), ('波', "bō"), ('浩', "hào"), ('凯', "kǎi"), ('健', "jiàn"), ('俊', "jùn"), ('帆', "fān"), ('旭', "xù"), ('锋', "fēng"),
+    ('峰', "fēng"), ('博', "bó"), ('毅', "yì"), ('彬', "bīn"), ('昊', "hào"), ('睿', "ruì"), ('哲', "zhé"), ('航', "háng"), ('铭', "míng"), ('泽', "zé"),
+    ('皓', "hào"), ('翔', "xiáng"), ('建', "jiàn"), ('志', "zhì"), ('永', "yǒng"), ('振', "zhèn"), ('兴', "xīng"), ('德', "dé"), ('豪', "háo"), ('力', "lì"),
+    ('亮', "liàng"), ('栋', "dòng"), ('瑞', "ruì"), ('晖', "huī"), ('诚', "chéng"), ('芳', "fāng"), ('娜', "nà"), ('敏', "mǐn"), ('静', "jìng"), ('丽', "lì"),
+    ('艳', "yàn"), ('娟', "juān"), ('霞', "xiá"), ('秀', "xiù"), ('玲', "líng"), ('萍', "píng"), ('琳', "lín"), ('雪', "xuě"), ('颖', "yǐng"), ('倩', "qiàn"),
+    ('婷', "tíng"), ('慧', "huì"), ('莉', "lì"), ('洁', "jié"), ('蓉', "róng"), ('媛', "yuàn"), ('薇', "wēi"), ('怡', "yí"), ('佳', "jiā"), ('欣', "xīn"),
+    ('悦', "yuè"), ('瑶', "yáo"), ('琪', "qí"), ('涵', "hán"), ('诗', "shī"), ('梦', "mèng"), ('雨', "yǔ"), ('露', "lù"), ('月', "yuè"), ('彤', "tóng"),
+    ('萱', "xuān"), ('妍', "yán"), ('璐', "lù"), ('菲', "fēi"), ('婉', "wǎn"), ('晴', "qíng"), ('蕾', "lěi"), ('菁', "jīng"), ('岚', "lán"), ('淑', "shū"),
+    ('珊', "shān"), ('婧', "jìng"), ('馨', "xīn"), ('茜', "qiàn"), ('钰', "yù"), ('曼', "màn"), ('莹', "yíng"), ('晨', "chén"), ('清', "qīng"), ('思', "sī"),
+    ('一', "yī"), ('嘉', "jiā"), ('可', "kě"), ('然', "rán"), ('心', "xīn"), ('逸', "yì"), ('天', "tiān"), ('新', "xīn"), ('星', "xīng"), ('远', "yuǎn"),
+    ('若', "ruò"), ('初', "chū"), ('辰', "chén"), ('佑', "yòu"), ('鸣', "míng"), ('晓', "xiǎo"), ('熙', "xī"), ('承', "chéng"), ('立', "lì"), ('梓', "zǐ"),
+    ('桐', "tóng"), ('语', "yǔ"), ('嫣', "yān"), ('曦', "xī"), ('英', "yīng"), ('玉', "yù"), ('兰', "lán"), ('珍', "zhēn"), ('美', "měi"), ('紫', "zǐ"),
+    ('诺', "nuò"), ('雅', "yǎ"), ('艺', "yì"), ('源', "yuán"), ('知', "zhī"),
+];
@@ -0,0 +1,225 @@
+use crate::random::{init_distribution, Gender, RandomKind};
+
+/// 建议允许的最大编辑距离, 实际阈值还随输入长度缩小, 每 3 个字符允许相差 1
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// 生成个数上限, 与直接输入数字时的 u8 范围一致
+const MAX_COUNT: usize = u8::MAX as usize;
+
+/// 这些词之后的一个数字是该词的参数, 不作为长度和个数, 例如 `number 0..1000 step 50`
+/// 分布名称之后的参数个数见 `init_distribution`
+const PARAMETER_KEYWORDS: [&str; 6] = ["step", "步长", "precision", "精度", "pad", "补零"];
+
+/// UUID 这些版本之后的词都是命名空间和名称, 数字也不作为个数, 例如 `uuid v5 dns 123`
+const NAME_KEYWORDS: [&str; 2] = ["v3", "v5"];
+
+/// 词的参数个数: `PARAMETER_KEYWORDS` 为 1 个, 分布为默认参数的个数
+fn parameter_count(word: &str) -> Option<usize> {
+    if PARAMETER_KEYWORDS.contains(&word) {
+        return Some(1);
+    }
+    init_distribution().into_iter()
+        .find(|(_, keywords, _)| keywords.contains(&word))
+        .map(|(_, _, defaults)| defaults.len())
+}
+
+/// 以这些词开头的查询校验数据而不是生成, 例如 `validate phone 13812345678`
+const VALIDATE_ALIASES: [&str; 6] = ["validate", "check", "校验", "验证", "jiaoyan", "jy"];
+
+/// Alfred 输入的完整查询, 第一个词为 kind, 其余为附加参数
+/// 例如 `phone 5`、`date 2020-01-01..2020-12-31`、`number 1..100 x20`、`email @example.com`
+pub enum Query {
+    /// kind 唯一确定, 直接生成
+    Generate { kind: RandomKind, options: Options },
+    /// 输入为空或匹配多个类型, 列出候选类型供选择, rest 为 kind 之后的原始参数
+    Choose { kinds: Vec<RandomKind>, rest: String },
+    /// 没有匹配的类型, 给出拼写相近的建议
+    NotFound { name: String, suggestions: Vec<RandomKind>, rest: String },
+    /// 校验 value 是否为合法的 kind, kind 不唯一时为 None
+    Validate { name: String, kind: Option<RandomKind>, value: String },
+}
+
+impl Query {
+    /// Alfred 可能把整个查询作为一个参数传入, 也可能已按空格拆分, 两种情况统一重新拆分
+    pub fn parse(kind: &str, tokens: &[String]) -> Query {
+        let mut tokens: Vec<String> = std::iter::once(kind)
+            .chain(tokens.iter().map(|t| t.as_str()))
+            .flat_map(|t| t.split_whitespace())
+            .map(|w| w.to_string())
+            .collect();
+        let name = if tokens.is_empty() { String::new() } else { tokens.remove(0) };
+        if VALIDATE_ALIASES.contains(&name.to_lowercase().as_str()) {
+            let name = if tokens.is_empty() { String::new() } else { tokens.remove(0) };
+            let kinds = RandomKind::get_random_by_name(&name);
+            let kind = if kinds.len() == 1 && !name.is_empty() { Some(kinds[0]) } else { None };
+            return Query::Validate { name, kind, value: tokens.join(" ") };
+        }
+        let mut kinds = RandomKind::get_random_by_name(&name);
+        match kinds.len() {
+            1 if !name.is_empty() => {
+                let kind = kinds.remove(0);
+                Query::Generate { kind, options: Options::parse(kind, &tokens) }
+            }
+            0 => Query::NotFound { suggestions: suggest(&name), name, rest: tokens.join(" ") },
+            _ => Query::Choose { kinds, rest: tokens.join(" ") },
+        }
+    }
+}
+
+/// 按与各别名的最小编辑距离由近到远给出最多 3 个建议
+/// 两个字母的拼音首字母缩写太短, 与任何输入都相近, 不参与比较
+fn suggest(name: &str) -> Vec<RandomKind> {
+    let name = name.to_lowercase();
+    let threshold = (name.chars().count() / 3).clamp(1, MAX_SUGGESTION_DISTANCE);
+    let mut kinds: Vec<(usize, RandomKind)> = RandomKind::all().into_iter()
+        .filter_map(|k| {
+            let distance = k.get_aliases().iter()
+                .filter(|alias| !(alias.is_ascii() && alias.len() <= 2))
+                .map(|alias| strsim::osa_distance(&name, alias))
+                .min()?;
+            Some((distance, k))
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .collect();
+    kinds.sort_by_key(|(distance, _)| *distance);
+    kinds.into_iter().take(3).map(|(_, k)| k).collect()
+}
+
+/// kind 之后的附加参数
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+    /// 长度, 含义由 kind 决定, 见 `RandomKind::has_length`
+    pub length: Option<usize>,
+    /// 生成个数, `x20` 或不超过 255 的整数, `x` 之后更大的数按 255 处理
+    pub count: Option<usize>,
+    /// `a..b` 形式的范围, 两端保留原始字符串由各生成器自行解析
+    pub range: Option<(String, String)>,
+    /// `@example.com` 形式的邮箱域名
+    pub domain: Option<String>,
+    /// 含 `%` 的 strftime 格式, 例如 `%Y/%m/%d`, 保留大小写
+    pub format: Option<String>,
+    /// 其他参数, 已转为小写
+    pub words: Vec<String>,
+    /// 与 words 一一对应的原始参数, 供区分大小写的场景使用, 例如 UUID v5 的名称
+    pub original_words: Vec<String>,
+}
+
+impl Options {
+    /// 不超过 255 的整数依次作为长度和个数, kind 没有长度含义时第一个整数即为个数
+    /// 随机数有范围或按分布生成时同样没有长度, 整数作为个数
+    /// 更大的整数 (例如年份) 和带 + 号的数字 (例如 +86) 与其他参数一样保留在 words 中
+    /// `PARAMETER_KEYWORDS` 之后该词参数个数以内的数字保留在 words 中, 作为该词的参数
+    /// UUID 的 `NAME_KEYWORDS` 之后的所有词都原样保留在 words 中
+    pub fn parse(kind: RandomKind, tokens: &[String]) -> Options {
+        let mut options = Options::default();
+        let mut pending_parameters = 0;
+        let mut in_name = false;
+        for token in tokens {
+            if in_name {
+                options.push_word(token);
+                continue;
+            }
+            if matches!(kind, RandomKind::Uuid) && NAME_KEYWORDS.contains(&token.to_lowercase().as_str()) {
+                in_name = true;
+                options.push_word(token);
+                continue;
+            }
+            if let Some(count) = parameter_count(&token.to_lowercase()) {
+                pending_parameters = count;
+                options.push_word(token);
+                continue;
+            }
+            if pending_parameters > 0 && token.parse::<f64>().is_ok() {
+                pending_parameters -= 1;
+                options.push_word(token);
+                continue;
+            }
+            pending_parameters = 0;
+            if let Some((start, end)) = token.split_once("..") {
+                options.range = Some((start.to_string(), end.to_string()));
+                continue;
+            }
+            if let Some(domain) = token.strip_prefix('@') {
+                options.domain = Some(domain.to_lowercase());
+                continue;
+            }
+            if token.contains('%') {
+                options.format = Some(token.to_string());
+                continue;
+            }
+            if let Some(count) = token.strip_prefix(['x', 'X', '×']).and_then(|n| n.parse::<u64>().ok()) {
+                options.count = Some(count.min(MAX_COUNT as u64) as usize);
+                continue;
+            }
+            match token.parse::<u8>().ok().filter(|_| !token.starts_with('+')) {
+                Some(n) if kind.has_length() && options.length.is_none() => options.length = Some(n as usize),
+                Some(n) if options.count.is_none() => options.count = Some(n as usize),
+                _ => options.push_word(token),
+            }
+        }
+        if options.length.is_some() && options.count.is_none() && !options.has_length(kind) {
+            options.count = options.length.take();
+        }
+        options
+    }
+
+    /// 随机数有范围或按分布生成时位数没有意义, 唯一的整数作为个数
+    fn has_length(&self, kind: RandomKind) -> bool {
+        match kind {
+            RandomKind::Number => self.range.is_none() && !init_distribution().iter().any(|(_, keywords, _)| self.has(keywords)),
+            kind => kind.has_length(),
+        }
+    }
+
+    fn push_word(&mut self, token: &str) {
+        self.words.push(token.to_lowercase());
+        self.original_words.push(token.to_string());
+    }
+
+    pub fn has(&self, names: &[&str]) -> bool {
+        self.words.iter().any(|w| names.contains(&w.as_str()))
+    }
+
+    /// 紧跟在 names 中某个词之后的连续数字参数
+    pub fn parameters(&self, names: &[&str]) -> Vec<&str> {
+        match self.words.iter().position(|w| names.contains(&w.as_str())) {
+            Some(i) => self.words[i + 1..].iter()
+                .map(|w| w.as_str())
+                .take_while(|w| w.parse::<f64>().is_ok())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn gender(&self) -> Option<Gender> {
+        if self.has(&["male", "m", "男"]) {
+            Some(Gender::Male)
+        } else if self.has(&["female", "f", "女"]) {
+            Some(Gender::Female)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(kind: RandomKind, query: &str) -> Options {
+        let tokens: Vec<String> = query.split_whitespace().map(|t| t.to_string()).collect();
+        Options::parse(kind, &tokens)
+    }
+
+    #[test]
+    fn parameters_take_only_their_own_numbers() {
+        let options = parse(RandomKind::Number, "1..100 step 5 20");
+        assert_eq!(options.parameters(&["step"]), vec!["5"]);
+        assert_eq!(options.count, Some(20));
+        let options = parse(RandomKind::Number, "normal 100 15 3");
+        assert_eq!(options.parameters(&["normal"]), vec!["100", "15"]);
+        assert_eq!(options.count, Some(3));
+        let options = parse(RandomKind::Number, "6 20");
+        assert_eq!((options.length, options.count), (Some(6), Some(20)));
+    }
+}
@@ -1,36 +1,103 @@
+use std::fmt::Write;
 use std::string::ToString;
-use uuid::Uuid;
-use chrono::{DateTime, NaiveTime, NaiveDate, Local, Duration};
-use rand::{Rng, thread_rng};
-use rand::distributions::Uniform;
+use std::sync::Mutex;
+use uuid::{Builder, Uuid};
+use chrono::{DateTime, NaiveTime, NaiveDate, NaiveDateTime, Local, Utc, Duration, Datelike, Months, FixedOffset, TimeZone};
+use chrono_tz::{Tz, TZ_VARIANTS};
+use chrono::format::{Item, StrftimeItems};
+use rand::{Rng, RngCore};
+use rand_distr::{Exp, LogNormal, Normal, Poisson, Zipf};
+use rand::distributions::{Distribution, Uniform, WeightedIndex};
 use rand::{distributions::Alphanumeric};
+use crate::{region, GenerateRandom};
+use crate::pinyin::NamePinyin;
+use crate::query::Options;
 
 #[derive(Debug, Clone, Copy)]
 pub enum RandomKind {
     Name,
     Email,
     Phone,
-    UUID,
+    Landline,
+    Uuid,
     Date,
     Time,
     DateTime,
     Number,
+    Money,
+    IdCard,
+    Uscc,
+    BankCard,
+    Plate,
+    Address,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gender {
+    Male,
+    Female,
 }
 
 impl RandomKind {
-    pub fn get_random_by_name(name: String) -> RandomKind {
+    pub fn all() -> Vec<RandomKind> {
+        vec![
+            RandomKind::Name,
+            RandomKind::Email,
+            RandomKind::Phone,
+            RandomKind::Landline,
+            RandomKind::Uuid,
+            RandomKind::Date,
+            RandomKind::Time,
+            RandomKind::DateTime,
+            RandomKind::Number,
+            RandomKind::Money,
+            RandomKind::IdCard,
+            RandomKind::Uscc,
+            RandomKind::BankCard,
+            RandomKind::Plate,
+            RandomKind::Address,
+        ]
+    }
+
+    /// 依次按以下规则匹配, 结果与类型声明顺序一致, name 为空时返回全部:
+    /// 1. 与某个别名完全相同, 只返回该类型
+    /// 2. 返回有别名以 name 开头的所有类型, 只有一个时即可直接生成
+    pub fn get_random_by_name(name: &str) -> Vec<RandomKind> {
         let name_lower = name.to_lowercase();
-        match name_lower.as_str() {
-            n if "name".starts_with(n) => RandomKind::Name,
-            e if "email".starts_with(e) => RandomKind::Email,
-            p if "phone".starts_with(p) => RandomKind::Phone,
-            u if "uuid".starts_with(u) => RandomKind::UUID,
-            d if "date".starts_with(d) => RandomKind::Date,
-            t if "time".starts_with(t) => RandomKind::Time,
-            d if "datetime".starts_with(d) => RandomKind::DateTime,
-            n if "number".starts_with(n) => RandomKind::Number,
-            _ => RandomKind::Name, // 默认返回 Name
+        let kinds = RandomKind::all();
+        if let Some(kind) = kinds.iter().find(|k| k.get_aliases().contains(&name_lower)) {
+            return vec![*kind];
         }
+        kinds.into_iter()
+            .filter(|k| k.get_aliases().iter().any(|alias| alias.starts_with(&name_lower)))
+            .collect()
+    }
+
+    /// 类型名称、简写、中文及拼音及拼音首字母, 第一个为 `get_name`
+    pub fn get_aliases(&self) -> Vec<String> {
+        let aliases: &[&str] = match self {
+            RandomKind::Name => &["name", "姓名", "名字", "xingming", "xm"],
+            RandomKind::Email => &["email", "mail", "邮箱", "邮件", "youxiang", "yx"],
+            RandomKind::Phone => &["phone", "mobile", "手机", "手机号", "shouji", "sj"],
+            RandomKind::Landline => &["landline", "tel", "座机", "固话", "固定电话", "zuoji", "zj", "guhua", "gh"],
+            RandomKind::Uuid => &["uuid", "guid"],
+            RandomKind::Date => &["date", "日期", "riqi", "rq"],
+            RandomKind::Time => &["time", "时间", "shijian"],
+            RandomKind::DateTime => &["datetime", "dt", "ts", "日期时间", "时间日期", "riqishijian", "rqsj"],
+            RandomKind::Number => &["number", "num", "数字", "随机数", "shuzi", "sz"],
+            RandomKind::Money => &["money", "amount", "rmb", "cny", "金额", "钱", "jine", "je"],
+            RandomKind::IdCard => &["idcard", "id", "身份证", "shenfenzheng", "sfz"],
+            RandomKind::Uscc => &["uscc", "信用代码", "统一社会信用代码", "xinyongdaima", "xydm"],
+            RandomKind::BankCard => &["bankcard", "card", "银行卡", "yinhangka", "yhk"],
+            RandomKind::Plate => &["plate", "车牌", "车牌号", "chepai", "cp"],
+            RandomKind::Address => &["address", "addr", "地址", "dizhi", "dz"],
+        };
+        aliases.iter().map(|a| a.to_string()).collect()
+    }
+
+    /// 长度参数是否有意义: 名字为名的字数, 邮件为用户名长度, 随机数为位数, 银行卡为卡号位数
+    pub fn has_length(&self) -> bool {
+        matches!(self, RandomKind::Name | RandomKind::Email | RandomKind::Number | RandomKind::BankCard)
     }
 
     pub fn get_name(&self) -> String {
@@ -38,78 +105,384 @@ impl RandomKind {
             RandomKind::Name => "name".to_string(),
             RandomKind::Email => "email".to_string(),
             RandomKind::Phone => "phone".to_string(),
-            RandomKind::UUID => "uuid".to_string(),
+            RandomKind::Landline => "landline".to_string(),
+            RandomKind::Uuid => "uuid".to_string(),
             RandomKind::Date => "date".to_string(),
             RandomKind::Time => "time".to_string(),
             RandomKind::DateTime => "datetime".to_string(),
             RandomKind::Number => "number".to_string(),
+            RandomKind::Money => "money".to_string(),
+            RandomKind::IdCard => "idcard".to_string(),
+            RandomKind::Uscc => "uscc".to_string(),
+            RandomKind::BankCard => "bankcard".to_string(),
+            RandomKind::Plate => "plate".to_string(),
+            RandomKind::Address => "address".to_string(),
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self {
+            RandomKind::Name => "生成中文名字".to_string(),
+            RandomKind::Email => "生成邮件".to_string(),
+            RandomKind::Phone => "生成手机号".to_string(),
+            RandomKind::Landline => "生成固定电话".to_string(),
+            RandomKind::Uuid => "生成UUID".to_string(),
+            RandomKind::Date => "生成随机日期".to_string(),
+            RandomKind::Time => "生成随机时间".to_string(),
+            RandomKind::DateTime => "生成随机时间日期".to_string(),
+            RandomKind::Number => "生成随机数".to_string(),
+            RandomKind::Money => "生成金额".to_string(),
+            RandomKind::IdCard => "生成身份证号".to_string(),
+            RandomKind::Uscc => "生成统一社会信用代码".to_string(),
+            RandomKind::BankCard => "生成银行卡号".to_string(),
+            RandomKind::Plate => "生成车牌号".to_string(),
+            RandomKind::Address => "生成地址".to_string(),
         }
     }
 }
 
-pub fn random_value(kind: RandomKind, length: i8) -> String {
+/// 所有随机数都取自 rng, 相同种子的 rng 生成相同的结果
+pub fn random_value(kind: RandomKind, options: &Options, rng: &mut impl Rng) -> GenerateRandom {
     match kind {
-        RandomKind::Name => random_name(),
-        RandomKind::Email => random_email(),
-        RandomKind::Phone => random_phone(),
-        RandomKind::UUID => random_uuid(),
-        RandomKind::Date => random_date(),
-        RandomKind::Time => random_time(),
-        RandomKind::DateTime => random_datetime(),
-        RandomKind::Number => random_number(length),
-    }
-}
-
-fn random_name() -> String {
-    let name_prefix_list = init_name_prefix();
-    let name_suffix_list = init_name_suffix();
-    let name_prefix = name_prefix_list[thread_rng().gen_range(0..name_prefix_list.len())].clone();
-    let name_suffix = name_suffix_list[thread_rng().gen_range(0..name_suffix_list.len())].clone();
-    let name_suffix = name_suffix + &*name_suffix_list[thread_rng().gen_range(0..name_suffix_list.len())].clone();
-    format!("{}{}", name_prefix, name_suffix)
-}
-
-fn random_email() -> String {
-    let mut rng = rand::thread_rng();
-    let domain_list = init_email_server();
-    let domain = domain_list[rand::thread_rng().gen_range(0..domain_list.len())].clone();
+        RandomKind::Name => random_name(options, rng),
+        RandomKind::Email if options.has(&["name", "姓名", "拼音", "pinyin"]) => random_name_email(options, rng),
+        RandomKind::Email => GenerateRandom::new(kind, random_email(options, rng)),
+        RandomKind::Phone => random_phone(options, rng),
+        RandomKind::Landline => random_landline(options, rng),
+        RandomKind::Uuid => random_uuid(options, rng),
+        RandomKind::Date => GenerateRandom::new(kind, random_date(options, rng)),
+        RandomKind::Time => GenerateRandom::new(kind, random_time(rng)),
+        RandomKind::DateTime => random_datetime(options, rng),
+        RandomKind::Number if init_distribution().iter().any(|(_, keywords, _)| options.has(keywords)) => random_distribution(options, rng),
+        RandomKind::Number => random_number(options, rng),
+        RandomKind::Money => random_money(options, rng),
+        RandomKind::IdCard => GenerateRandom::new(kind, random_id_card(options, rng)),
+        RandomKind::Uscc => GenerateRandom::new(kind, random_uscc(options, rng)),
+        RandomKind::BankCard => random_bank_card(options, rng),
+        RandomKind::Plate => random_plate(options, rng),
+        RandomKind::Address => random_address(options, rng),
+    }
+}
+
+/// 按人口占比随机姓氏, 可用 `复姓` 只使用复姓, `生僻` 在少见姓氏中等概率选取
+fn random_surname(options: &Options, rng: &mut impl Rng) -> String {
+    let mut surnames = init_name_prefix();
+    if options.has(&["compound", "复姓"]) {
+        surnames.retain(|(s, _)| s.chars().count() > 1);
+    }
+    if options.has(&["rare", "生僻", "少见"]) {
+        surnames.retain(|(_, weight)| *weight <= 10);
+        surnames.iter_mut().for_each(|entry| entry.1 = 1);
+    }
+    let dist = WeightedIndex::new(surnames.iter().map(|(_, weight)| *weight)).unwrap();
+    surnames[dist.sample(rng)].0.clone()
+}
+
+/// 中文名字, 拼音作为备选操作
+fn random_name(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let (name_prefix, name_suffix, subtitle) = random_full_name(options, rng);
+    let value = GenerateRandom::new(RandomKind::Name, format!("{}{}", name_prefix, name_suffix))
+        .with_subtitle(format!("name {}", subtitle));
+    match NamePinyin::new(&name_prefix, &name_suffix) {
+        Some(pinyin) => value
+            .with_alternative("拼音".to_string(), pinyin.capitalized())
+            .with_alternative("用户名".to_string(), pinyin.compact())
+            .with_alternative("护照".to_string(), pinyin.surname_upper())
+            .with_alternative("声调".to_string(), pinyin.with_tone()),
+        None => value,
+    }
+}
+
+/// 姓 + 名, 名按性别从常见名字和常用字中选取, 可用 `男`/`女` 指定性别, `中性` 只使用男女通用的名字
+/// length 为名的字数, 默认约 15% 为单字名, 返回 (姓, 名, 性别)
+fn random_full_name(options: &Options, rng: &mut impl Rng) -> (String, String, &'static str) {
+    let name_prefix = random_surname(options, rng);
+
+    let neutral = options.has(&["neutral", "中性"]);
+    let gender = options.gender().unwrap_or(if rng.gen_bool(0.5) { Gender::Male } else { Gender::Female });
+    let mut given_names = init_given_name(None);
+    let mut chars = init_given_name_char(None);
+    if !neutral {
+        given_names.extend(init_given_name(Some(gender)));
+        chars.extend(init_given_name_char(Some(gender)));
+    }
+
+    let length = options.length.unwrap_or(if rng.gen_bool(0.15) { 1 } else { 2 }).max(1);
+    let name_suffix = if length == 2 && rng.gen_bool(0.5) {
+        given_names[rng.gen_range(0..given_names.len())].clone()
+    } else {
+        let mut name_suffix = String::new();
+        while name_suffix.chars().count() < length {
+            let c = &chars[rng.gen_range(0..chars.len())];
+            // 避免叠字, 字数超过字池大小时允许重复
+            if !name_suffix.contains(c.as_str()) || length > chars.len() {
+                name_suffix.push_str(c);
+            }
+        }
+        name_suffix
+    };
+    let subtitle = match (neutral, gender) {
+        (true, _) => "中性",
+        (false, Gender::Male) => "男",
+        (false, Gender::Female) => "女",
+    };
+    (name_prefix, name_suffix, subtitle)
+}
+
+/// length 为 @ 之前用户名的长度, 默认 10 位
+fn random_email(options: &Options, rng: &mut impl Rng) -> String {
+    let length = options.length.unwrap_or(10);
+    let domain = random_email_domain(options, rng);
     let email_name: String = std::iter::repeat(())
         .map(|()| rng.sample(Alphanumeric))
         .map(|c| c as char)
-        .take(10) // 随机电子邮件名称的长度
+        .take(length.max(1))
         .collect();
     format!("{}@{}", email_name, domain)
 }
 
-fn random_phone() -> String {
-    let mut rng = thread_rng();
-    let phone_fix_list = init_phone_prefix();
-    let phone_fix = phone_fix_list[rand::thread_rng().gen_range(0..phone_fix_list.len())].clone();
-    let suffix: String = (0..8)
+/// `@域名` 优先, `安全` 使用不会真实投递的保留域名, `公司` 使用自定义域名, 否则从常见邮箱服务商中随机
+fn random_email_domain(options: &Options, rng: &mut impl Rng) -> String {
+    if let Some(domain) = &options.domain {
+        return domain.clone();
+    }
+    let domain_list = if options.has(&["safe", "安全", "测试"]) {
+        init_safe_email_server()
+    } else if options.has(&["corp", "company", "公司", "企业"]) {
+        // 未配置自定义域名时退回保留域名, 避免发到真实邮箱
+        let custom_list = init_custom_email_server();
+        if custom_list.is_empty() { init_safe_email_server() } else { custom_list }
+    } else {
+        init_email_server()
+    };
+    domain_list[rng.gen_range(0..domain_list.len())].clone()
+}
+
+/// 用户名取自随机名字的拼音, 例如 zhang.san、zsan1992、san_zhang, 可能附加出生年份或数字
+fn random_name_email(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let (surname, given_name, _) = random_full_name(options, rng);
+    let name = format!("{}{}", surname, given_name);
+    let Some(pinyin) = NamePinyin::new(&surname, &given_name) else {
+        return GenerateRandom::new(RandomKind::Email, random_email(options, rng));
+    };
+    let (surname, given_name) = (pinyin.surname(), pinyin.given_name());
+    let initial = &surname[..1];
+    let email_name = match rng.gen_range(0..5) {
+        0 => format!("{}.{}", surname, given_name),
+        1 => format!("{}_{}", given_name, surname),
+        2 => format!("{}{}", initial, given_name),
+        3 => format!("{}{}", given_name, initial),
+        _ => format!("{}{}", surname, given_name),
+    };
+    let suffix = match rng.gen_range(0..3) {
+        0 => rng.gen_range(1960..=2005).to_string(),
+        1 => rng.gen_range(1..1000).to_string(),
+        _ => String::new(),
+    };
+    let domain = random_email_domain(options, rng);
+    GenerateRandom::new(RandomKind::Email, format!("{}{}@{}", email_name, suffix, domain))
+        .with_subtitle(format!("email {}", name))
+}
+
+/// 可用 `移动`/`cm`、`联通`/`cu`、`电信`/`ct`、`广电`/`cbn`、`虚拟`/`mvno` 指定运营商, 副标题为运营商
+/// 可用 `空格`、`+86`、`横线`、`括号` 指定格式, 其余格式作为备选操作
+fn random_phone(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let carriers: Vec<String> = init_phone_carrier().into_iter()
+        .filter(|(_, keywords)| options.words.iter().any(|w| keywords.contains(&w.to_lowercase())))
+        .map(|(code, _)| code)
+        .collect();
+    let prefixes: Vec<_> = init_phone_prefix().into_iter()
+        .filter(|(code, ..)| carriers.is_empty() || carriers.contains(code))
+        .collect();
+    let (_, carrier, prefix) = &prefixes[rng.gen_range(0..prefixes.len())];
+    let suffix: String = (prefix.len()..11)
         .map(|_| rng.gen_range(0..10))
         .map(|i| i.to_string())
         .collect();
-    format!("{}{}", phone_fix, suffix)
+    let phone = format!("{}{}", prefix, suffix);
+
+    with_formats(RandomKind::Phone, options, &init_phone_format(), |name| format_phone(&phone, name))
+        .with_subtitle(format!("phone {}", carrier))
+}
+
+/// 按查询关键字选出输出格式, 其余格式作为备选操作, 没有关键字时使用第一个格式
+fn with_formats(kind: RandomKind, options: &Options, formats: &[(&str, &[&str])], format: impl Fn(&str) -> String) -> GenerateRandom {
+    let selected = formats.iter().position(|(_, keywords)| options.has(keywords)).unwrap_or(0);
+    let mut value = GenerateRandom::new(kind, format(formats[selected].0));
+    for (i, (name, _)) in formats.iter().enumerate() {
+        if i != selected {
+            value = value.with_alternative(name.to_string(), format(name));
+        }
+    }
+    value
+}
+
+/// 按格式名称格式化 11 位手机号
+fn format_phone(phone: &str, format: &str) -> String {
+    let (head, middle, tail) = (&phone[..3], &phone[3..7], &phone[7..]);
+    match format {
+        "国际空格" => format!("+86 {} {} {}", head, middle, tail),
+        "国际" => format!("+86{}", phone),
+        "横线" => format!("{}-{}-{}", head, middle, tail),
+        "括号" => format!("(+86) {}", phone),
+        _ => phone.to_string(),
+    }
+}
+
+/// 默认为随机的 v4, 可用 `v1`、`v3`、`v4`、`v5`、`v6`、`v7`、`nil`、`max` 指定版本
+/// v3/v5 的命名空间为 `dns`、`url`、`oid`、`x.500` 或一个 UUID, 之后的参数为名称, 例如 `v5 dns example.com`
+/// 可用 `大写`、`无横线`、`大括号`、`urn` 指定格式, 其余格式作为备选操作
+fn random_uuid(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    const VERSIONS: [&str; 8] = ["v1", "v3", "v4", "v5", "v6", "v7", "nil", "max"];
+    let (position, version) = options.words.iter().enumerate()
+        .find_map(|(i, w)| VERSIONS.iter().find(|v| *v == w).map(|v| (i, *v)))
+        .unwrap_or((options.words.len(), "v4"));
+    // v3/v5 之后的命名空间和名称不作为格式关键字
+    let mut format_options = options.clone();
+    if matches!(version, "v3" | "v5") {
+        format_options.words.truncate(position + 1);
+    }
+    // 1582-10-15 到 1970-01-01 的 100 纳秒数
+    const GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+    let now = Utc::now();
+    let ticks = now.timestamp_nanos_opt().unwrap_or_default() as u64 / 100 + GREGORIAN_OFFSET;
+    // 随机节点号需要置多播位, 以免与真实网卡地址冲突
+    let mut node_id: [u8; 6] = rng.gen();
+    node_id[0] |= 0x01;
+    let mut subtitle = format!("uuid {}", version);
+    let uuid = match version {
+        "v1" => Builder::from_gregorian_timestamp(ticks, rng.gen(), &node_id).into_uuid(),
+        "v6" => Builder::from_sorted_gregorian_timestamp(ticks, rng.gen(), &node_id).into_uuid(),
+        "v7" => next_v7(now.timestamp_millis() as u64, rng),
+        "nil" => Uuid::nil(),
+        "max" => Uuid::max(),
+        "v3" | "v5" => {
+            let namespace_word = options.words.get(position + 1).map(String::as_str).unwrap_or("dns");
+            let namespace = match namespace_word {
+                "dns" => Some(Uuid::NAMESPACE_DNS),
+                "url" => Some(Uuid::NAMESPACE_URL),
+                "oid" => Some(Uuid::NAMESPACE_OID),
+                "x.500" | "x500dn" => Some(Uuid::NAMESPACE_X500),
+                word => Uuid::parse_str(word).ok(),
+            };
+            // 没有命名空间时按 dns 处理, 之后的参数都作为名称
+            let name_start = position + if namespace.is_some() { 2 } else { 1 };
+            let namespace = namespace.unwrap_or(Uuid::NAMESPACE_DNS);
+            let name = options.original_words.get(name_start..).unwrap_or_default().join(" ");
+            subtitle = format!("uuid {} {}", version, name);
+            if version == "v3" { Uuid::new_v3(&namespace, name.as_bytes()) } else { Uuid::new_v5(&namespace, name.as_bytes()) }
+        }
+        _ => Builder::from_random_bytes(rng.gen()).into_uuid(),
+    };
+
+    let format = |name: &str| match name {
+        "大写" => uuid.hyphenated().encode_upper(&mut Uuid::encode_buffer()).to_string(),
+        "无横线" => uuid.simple().to_string(),
+        "大括号" => uuid.braced().to_string(),
+        "URN" => uuid.urn().to_string(),
+        _ => uuid.hyphenated().to_string(),
+    };
+    with_formats(RandomKind::Uuid, &format_options, &init_uuid_format(), format)
+        .with_subtitle(subtitle)
+}
+
+/// 上一个 v7 UUID 的时间戳和随机部分, 毫秒时间戳在高位
+static LAST_V7: Mutex<u128> = Mutex::new(0);
+
+/// v7 为 48 位毫秒时间戳加 74 位随机数; 不大于上一个时在上一个的基础上加一个随机增量, 进位到时间戳,
+/// 保证同一批结果单调递增
+fn next_v7(millis: u64, rng: &mut impl Rng) -> Uuid {
+    const RANDOM_BITS: u32 = 74;
+    let mut last = LAST_V7.lock().unwrap_or_else(|e| e.into_inner());
+    let mut state = (u128::from(millis) << RANDOM_BITS) | (rng.gen::<u128>() >> (128 - RANDOM_BITS));
+    if state <= *last {
+        state = *last + rng.gen_range(1..1u128 << 32);
+    }
+    *last = state;
+    let (millis, random) = (state >> RANDOM_BITS, state & ((1 << RANDOM_BITS) - 1));
+    Uuid::from_u128(millis << 80 | 0x7 << 76 | (random >> 62) << 64 | 0b10 << 62 | (random & ((1 << 62) - 1)))
+}
+
+/// 默认在 2000-01-01 到今天之间, 可用 `2020-01-01..2020-12-31` 或相对今天的 `-30d..+30d` 指定范围 (含两端)
+/// 缺省的一端使用默认值
+/// 格式可用 `iso`、`cn`、`us`、`compact` 或 strftime 格式指定, 含时间或时区等日期没有的字段时使用默认格式
+fn random_date(options: &Options, rng: &mut impl Rng) -> String {
+    let today = Local::now().date_naive();
+    let mut start_date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    let mut end_date = today;
+    if let Some((start, end)) = &options.range {
+        start_date = parse_date(start, today).unwrap_or(start_date);
+        end_date = parse_date(end, today).unwrap_or(end_date);
+    }
+    let (start_date, end_date) = (start_date.min(end_date), start_date.max(end_date));
+    let days = (end_date - start_date).num_days();
+    let random_date = start_date + Duration::days(rng.gen_range(0..=days));
+    let mut text = String::new();
+    match write!(text, "{}", random_date.format(&date_format(options, "%Y-%m-%d"))) {
+        Ok(()) => text,
+        Err(_) => random_date.format("%Y-%m-%d").to_string(),
+    }
+}
+
+/// 绝对日期 (`2024-09-26`、`2024/09/26`、`20240926`), `today`/`今天`、`yesterday`/`昨天`、`tomorrow`/`明天`,
+/// 或相对 today 的偏移 (`-30d`、`+2w`、`+6m`、`-1y`, 单位为天、周、月、年, 也可写作 `-30天`)
+fn parse_date(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    if let Some(date) = ["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"].iter().find_map(|f| NaiveDate::parse_from_str(text, f).ok()) {
+        return Some(date);
+    }
+    match text.to_lowercase().as_str() {
+        "today" | "now" | "今天" => return Some(today),
+        "yesterday" | "昨天" => return today.pred_opt(),
+        "tomorrow" | "明天" => return today.succ_opt(),
+        _ => {}
+    }
+    let (index, unit) = text.char_indices().last()?;
+    let amount: i32 = text[..index].parse().ok()?;
+    let months = match unit.to_ascii_lowercase() {
+        'd' | '天' => return today.checked_add_signed(Duration::days(amount as i64)),
+        'w' | '周' => return today.checked_add_signed(Duration::weeks(amount as i64)),
+        'm' | '月' => amount,
+        'y' | '年' => amount.checked_mul(12)?,
+        _ => return None,
+    };
+    if months < 0 {
+        today.checked_sub_months(Months::new(months.unsigned_abs()))
+    } else {
+        today.checked_add_months(Months::new(months as u32))
+    }
 }
 
-fn random_uuid() -> String {
-    Uuid::new_v4().to_string()
+/// 日期格式预设: iso `2024-09-26`, cn `2024年9月26日`, us `09/26/2024`, compact `20240926`
+/// 查询中的 strftime 格式优先, 格式无效或都没有时使用 default
+fn date_format(options: &Options, default: &str) -> String {
+    if let Some(format) = strftime_format(options) {
+        return format;
+    }
+    let presets = [
+        (&["iso"][..], "%Y-%m-%d"),
+        (&["cn", "中文"][..], "%Y年%-m月%-d日"),
+        (&["us", "美式"][..], "%m/%d/%Y"),
+        (&["compact", "紧凑"][..], "%Y%m%d"),
+    ];
+    presets.iter()
+        .find(|(names, _)| options.has(names))
+        .map(|(_, format)| format.to_string())
+        .unwrap_or_else(|| default.to_string())
 }
 
-fn random_date() -> String {
-    let start_date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
-    let end_date = Local::now().date_naive();
-    let random_date = start_date + Duration::days(rand::thread_rng().gen_range(0..(end_date - start_date).num_days()));
-    random_date.format("%Y-%m-%d").to_string()
+/// 查询中有效的 strftime 格式
+fn strftime_format(options: &Options) -> Option<String> {
+    options.format.clone()
+        .filter(|format| !StrftimeItems::new(format).any(|item| item == Item::Error))
 }
 
-fn random_time() -> String {
+fn random_time(rng: &mut impl Rng) -> String {
     // 生成一个随机的小时数（0-23）
-    let hour = rand::thread_rng().gen_range(0..24);
+    let hour = rng.gen_range(0..24);
     // 生成一个随机的分钟数（0-59）
-    let minute = rand::thread_rng().gen_range(0..60);
+    let minute = rng.gen_range(0..60);
     // 生成一个随机的秒数（0-59）
-    let second = rand::thread_rng().gen_range(0..60);
+    let second = rng.gen_range(0..60);
 
     // 创建一个随机的 NaiveTime 对象
     let random_time = NaiveTime::from_hms_opt(hour, minute, second);
@@ -118,154 +491,1164 @@ fn random_time() -> String {
 }
 
 
-fn random_datetime() -> String {
-    let start = DateTime::from_timestamp(946656000, 0); // 2000年1月1日
-    let end = DateTime::from_timestamp(1670000000, 0); // 2023年1月1日
-    let duration = end.unwrap() - start.unwrap();
-    let seconds = rand::thread_rng().gen_range(0..i64::from(duration.num_seconds()));
-    let random_date = start.unwrap() + chrono::Duration::seconds(seconds);
-    random_date.format("%Y-%m-%d %H:%M:%S").to_string()
+/// 默认在 2000-01-01 到现在之间, 可用 `2024-01-01..2024-06-30T18:00:00` 或相对现在的 `-2h..+7d` 指定范围 (含两端)
+/// 相对偏移单位为秒 s、分 min、时 h、天 d、周 w、月 m、年 y, 缺省的一端使用默认值
+/// 时区可用 `utc`、`asia/shanghai` 等 IANA 名称或 `+08:00`、`utc-5` 等偏移, 默认本地时区
+/// 可用 `rfc3339`、`rfc2822`、`timestamp` 或 strftime 格式指定输出, 其余格式作为备选操作
+fn random_datetime(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let (zone_name, zone) = options.words.iter()
+        .find_map(|w| parse_time_zone(w).map(|zone| (w.clone(), zone)))
+        .unwrap_or(("local".to_string(), Zone::Local));
+    // 去掉纳秒, 与范围两端和输出的精度一致
+    let now = zone.convert(DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap());
+    let mut start = zone.resolve(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().into()).unwrap_or(now);
+    let mut end = now;
+    if let Some((start_text, end_text)) = &options.range {
+        start = parse_datetime(start_text, now, &zone).unwrap_or(start);
+        end = parse_datetime(end_text, now, &zone).unwrap_or(end);
+    }
+    let (start, end) = (start.min(end), start.max(end));
+    let seconds = rng.gen_range(0..=(end - start).num_seconds());
+    let random_datetime = start + Duration::seconds(seconds);
+
+    let format = |name: &str| match name {
+        "RFC 3339" => random_datetime.to_rfc3339(),
+        "RFC 2822" => random_datetime.to_rfc2822(),
+        "时间戳" => random_datetime.timestamp().to_string(),
+        _ => random_datetime.format(&strftime_format(options).unwrap_or("%Y-%m-%d %H:%M:%S".to_string())).to_string(),
+    };
+    with_formats(RandomKind::DateTime, options, &init_datetime_format(), format)
+        .with_subtitle(format!("datetime {}", zone_name))
+}
+
+/// 时间日期的输出格式及查询关键字, 第一个为默认格式
+fn init_datetime_format() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("时间日期", &["plain", "时间日期"]),
+        ("RFC 3339", &["rfc3339", "iso", "iso8601"]),
+        ("RFC 2822", &["rfc2822", "email"]),
+        ("时间戳", &["timestamp", "unix", "时间戳"]),
+    ]
 }
 
-fn random_number(length: i8) -> String {
-    let length = if length < 0 { 9 } else { length } as usize;
-    let mut rng = rand::thread_rng();
-    let digit_dist = Uniform::new(0, 10);
-    (0..length).map(|_| rng.sample(digit_dist)).map(|i| i.to_string()).collect()
+/// 时间日期所在的时区
+enum Zone {
+    Local,
+    Named(Tz),
+    Fixed(FixedOffset),
 }
 
-fn init_phone_prefix() -> Vec<String> {
-    let mut phone_prefix = Vec::new();
-    // 循环生成 130-139 的前缀
-    for i in 30..=39 {
-        let prefix = format!("13{}", i);
-        phone_prefix.push(prefix);
+impl Zone {
+    fn convert(&self, datetime: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Local => datetime.with_timezone(&Local).fixed_offset(),
+            Zone::Named(tz) => datetime.with_timezone(tz).fixed_offset(),
+            Zone::Fixed(offset) => datetime.with_timezone(offset),
+        }
     }
 
-    // 单独添加 144 的前缀
-    phone_prefix.push("144".to_string());
+    /// 夏令时重叠时取较早的时间, 不存在的时间返回 None
+    fn resolve(&self, datetime: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            Zone::Local => Local.from_local_datetime(&datetime).earliest().map(|d| d.fixed_offset()),
+            Zone::Named(tz) => tz.from_local_datetime(&datetime).earliest().map(|d| d.fixed_offset()),
+            Zone::Fixed(offset) => offset.from_local_datetime(&datetime).earliest(),
+        }
+    }
+}
 
-    // 循环生成 145-149 的前缀
-    for i in 5..=9 {
-        phone_prefix.push(format!("14{}", i));
+/// `local`/`本地`、`utc`/`gmt`、IANA 名称 (不区分大小写) 或 `+08:00`、`+8`、`utc+8`、`-0530` 形式的偏移
+fn parse_time_zone(text: &str) -> Option<Zone> {
+    match text {
+        "local" | "本地" => return Some(Zone::Local),
+        "utc" | "gmt" | "z" => return Some(Zone::Fixed(FixedOffset::east_opt(0)?)),
+        _ => {}
+    }
+    if text.contains('/') {
+        return TZ_VARIANTS.iter().find(|tz| tz.name().eq_ignore_ascii_case(text)).map(|tz| Zone::Named(*tz));
     }
+    let offset = text.strip_prefix("utc").or_else(|| text.strip_prefix("gmt")).unwrap_or(text);
+    let sign = match offset.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits = offset[1..].replace(':', "");
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (digits[..2].parse::<i32>().ok()?, digits[2..].parse::<i32>().ok()?),
+        _ => return None,
+    };
+    if minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).map(Zone::Fixed)
+}
 
-    // 循环生成 150-159 的前缀
-    for i in 0..=9 {
-        let prefix = format!("15{}", i);
-        phone_prefix.push(prefix);
+/// `now`/`现在`, RFC 3339, 所在时区的 `2024-01-01T08:00:00` 或日期 (当天零点),
+/// 以及相对 now 的偏移 (`-90s`、`+30min`、`-2h`, 也可写作 `-2小时`, 天以上的单位同 `parse_date`)
+fn parse_datetime(text: &str, now: DateTime<FixedOffset>, zone: &Zone) -> Option<DateTime<FixedOffset>> {
+    if ["now", "现在"].contains(&text.to_lowercase().as_str()) {
+        return Some(now);
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return Some(datetime);
+    }
+    if let Some(datetime) = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"].iter().find_map(|f| NaiveDateTime::parse_from_str(text, f).ok()) {
+        return zone.resolve(datetime);
+    }
+    if text.starts_with(['+', '-']) {
+        let lower = text.to_lowercase();
+        let (amount, unit) = match lower.find(|c: char| c.is_alphabetic()) {
+            Some(i) => lower.split_at(i),
+            None => return None,
+        };
+        let amount: i64 = amount.parse().ok()?;
+        // 超出 Duration 范围的偏移视为无效
+        let duration = match unit {
+            "s" | "秒" => Some(Duration::try_seconds(amount)?),
+            "min" | "分" | "分钟" => Some(Duration::try_minutes(amount)?),
+            "h" | "时" | "小时" => Some(Duration::try_hours(amount)?),
+            _ => None,
+        };
+        if let Some(duration) = duration {
+            return now.checked_add_signed(duration);
+        }
+        let date = parse_date(text, now.date_naive())?;
+        return zone.resolve(date.and_time(now.time()));
+    }
+    zone.resolve(parse_date(text, now.date_naive())?.into())
+}
+
+/// 默认生成 length 位数字, 首位不为 0; 可用 `1..100`、`-5..5`、`0.5..2.75` 指定范围 (含两端), 缺省的一端为 0 和 i64::MAX
+/// 范围内可用 `step 50` 指定步长, `precision 2` 指定小数位数, 默认取两端和步长中最多的小数位数
+/// 两端按十进制精确解析并向内取整, 范围内没有该精度的数时提高精度, 超出 i64 的范围改用默认范围, 副标题说明实际使用的值
+/// `pad 6` 把整数部分补零到 6 位, 没有范围和长度时生成 6 位可以 0 开头的数字; 只写 `pad` 时补零到 length 或范围上限的位数
+fn random_number(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let pad = options.parameters(&["pad", "补零"]).first().and_then(|w| w.parse::<usize>().ok());
+    let Some((start, end)) = &options.range else {
+        let length = options.length.or(pad).unwrap_or(9).max(1);
+        let digit_dist = Uniform::new(0, 10);
+        let first = if options.has(&["pad", "补零"]) { rng.sample(digit_dist) } else { rng.gen_range(1..10) };
+        let digits: String = std::iter::once(first)
+            .chain((1..length).map(|_| rng.sample(digit_dist)))
+            .map(|i| i.to_string())
+            .collect();
+        return GenerateRandom::new(RandomKind::Number, format!("{:0>width$}", digits, width = pad.unwrap_or(0)));
+    };
+    let step = options.parameters(&["step", "步长"]).first().and_then(|w| Decimal::parse(w));
+    let (start, end) = (Decimal::parse(start), Decimal::parse(end));
+    let precision = options.parameters(&["precision", "精度"]).first()
+        .and_then(|w| w.parse::<usize>().ok())
+        .unwrap_or_else(|| [&start, &end, &step].into_iter().flatten().map(Decimal::decimals).max().unwrap_or(0))
+        .min(15);
+    // 超出 i64 的边界改用默认范围
+    let mut subtitle = None;
+    let default = (Decimal::from(0), Decimal::from(i64::MAX));
+    let (start, end) = match (start.unwrap_or(default.0), end.unwrap_or(default.1)) {
+        (start, end) if start.in_i64() && end.in_i64() => (start, end),
+        _ => {
+            subtitle = Some(format!("范围超出 i64, 使用 0..{}", i64::MAX));
+            default
+        }
+    };
+    let (start, end) = if start.scaled(15, false) > end.scaled(15, false) { (end, start) } else { (start, end) };
+    // 两端向内取整, 保证结果不超出范围; 该精度下范围内没有数时逐步提高精度
+    let inward = |precision: usize| (start.scaled(precision, true), end.scaled(precision, false));
+    let (precision, (start, end)) = match (precision..=15).map(|p| (p, inward(p))).find(|(_, (start, end))| start <= end) {
+        Some((actual, range)) if actual == precision => (actual, range),
+        Some((actual, range)) => {
+            subtitle = Some(format!("{} 位小数内没有可选的数, 使用 {} 位小数", precision, actual));
+            (actual, range)
+        }
+        None => {
+            subtitle = Some("15 位小数内没有可选的数, 使用最接近的数".to_string());
+            let nearest = start.scaled(15, false);
+            (15, (nearest, nearest))
+        }
+    };
+    let scale = 10i128.pow(precision as u32);
+    let step = step.map(|s| s.scaled(precision, false).abs())
+        .filter(|s| *s > 0)
+        .unwrap_or(1);
+    let value = start + rng.gen_range(0..=(end - start) / step) * step;
+
+    let pad = match pad {
+        Some(width) => width,
+        None if options.has(&["pad", "补零"]) => (end.abs() / scale).to_string().len(),
+        None => 0,
+    };
+    let sign = if value < 0 { "-" } else { "" };
+    let (integer, fraction) = (value.abs() / scale, value.abs() % scale);
+    let value = if precision == 0 {
+        format!("{}{:0pad$}", sign, integer, pad = pad)
+    } else {
+        format!("{}{:0pad$}.{:0precision$}", sign, integer, fraction, pad = pad, precision = precision)
+    };
+    match subtitle {
+        Some(subtitle) => GenerateRandom::new(RandomKind::Number, value).with_subtitle(subtitle),
+        None => GenerateRandom::new(RandomKind::Number, value),
+    }
+}
+
+/// 十进制数 mantissa × 10^exponent, 范围两端不经过 f64, 超过 2^53 的整数和小数都能精确比较
+#[derive(Clone, Copy)]
+struct Decimal {
+    mantissa: i128,
+    exponent: i32,
+}
+
+impl Decimal {
+    /// `-12`、`0.05`、`1.5e3` 形式的十进制数, 有效数字不超过 i128 的范围
+    fn parse(text: &str) -> Option<Decimal> {
+        let (number, exponent) = match text.split_once(['e', 'E']) {
+            Some((number, exponent)) => (number, exponent.parse::<i32>().ok()?),
+            None => (text, 0),
+        };
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => (-1, digits),
+            None => (1, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let all = format!("{}{}", integer, fraction);
+        if all.is_empty() || !all.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mantissa = all.parse::<i128>().ok()?;
+        let exponent = exponent.checked_sub(i32::try_from(fraction.len()).ok()?)?;
+        Some(Decimal { mantissa: sign * mantissa, exponent })
+    }
+
+    /// 小数位数
+    fn decimals(&self) -> usize {
+        self.exponent.min(0).unsigned_abs() as usize
+    }
+
+    /// 乘以 10^precision 后取整, ceil 为 true 时向上取整, 否则向下取整; 超出 i128 时饱和
+    fn scaled(&self, precision: usize, ceil: bool) -> i128 {
+        let shift = self.exponent.saturating_add(precision as i32);
+        if shift >= 0 {
+            let saturated = if self.mantissa < 0 { i128::MIN } else { i128::MAX };
+            return 10i128.checked_pow(shift as u32)
+                .and_then(|factor| self.mantissa.checked_mul(factor))
+                .unwrap_or(if self.mantissa == 0 { 0 } else { saturated });
+        }
+        let (floor, exact) = match 10i128.checked_pow(shift.unsigned_abs()) {
+            Some(divisor) => (self.mantissa.div_euclid(divisor), self.mantissa.rem_euclid(divisor) == 0),
+            None => (if self.mantissa < 0 { -1 } else { 0 }, self.mantissa == 0),
+        };
+        if ceil && !exact { floor + 1 } else { floor }
+    }
+
+    /// 整数部分在 ±i64::MAX 之内
+    fn in_i64(&self) -> bool {
+        let limit = i64::MAX as i128;
+        self.scaled(0, false) >= -limit && self.scaled(0, true) <= limit
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Decimal {
+        Decimal { mantissa: value as i128, exponent: 0 }
+    }
+}
+
+/// 按分布随机, 例如 `normal 100 15`, 参数缺省或无效时使用默认参数, 副标题为实际使用的分布和参数
+/// 连续分布默认保留 2 位小数, 可用 `precision` 指定; 泊松分布和齐夫分布为整数
+fn random_distribution(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let (name, keywords, defaults) = init_distribution().into_iter()
+        .find(|(_, keywords, _)| options.has(keywords))
+        .unwrap();
+    let mut parameters: Vec<f64> = options.parameters(keywords).iter().filter_map(|w| w.parse().ok()).collect();
+    parameters.truncate(defaults.len());
+    parameters.extend_from_slice(&defaults[parameters.len()..]);
+    let precision = options.parameters(&["precision", "精度"]).first()
+        .and_then(|w| w.parse::<usize>().ok())
+        .unwrap_or(2)
+        .min(15);
+
+    let sample = |parameters: &[f64], rng: &mut dyn RngCore| -> Option<String> {
+        let (a, b) = (parameters[0], parameters.get(1).copied().unwrap_or_default());
+        let value = match name {
+            // rand_distr 允许负的标准差和为 0 的 λ, 这里视为无效
+            "normal" => Normal::new(a, b).ok().filter(|_| b >= 0.0)?.sample(rng),
+            "lognormal" => LogNormal::new(a, b).ok().filter(|_| b >= 0.0)?.sample(rng),
+            "exponential" => Exp::new(a).ok().filter(|_| a > 0.0)?.sample(rng),
+            "poisson" => return Some(Poisson::new(a).ok()?.sample(rng).to_string()),
+            _ => return Some(Zipf::new(a as u64, b).ok()?.sample(rng).to_string()),
+        };
+        Some(format!("{:.precision$}", value, precision = precision))
+    };
+    let describe = |parameters: &[f64]| {
+        let parameters: Vec<String> = parameters.iter().map(|p| p.to_string()).collect();
+        format!("{} {}", name, parameters.join(" "))
+    };
+    let (value, subtitle) = match sample(&parameters, rng) {
+        Some(value) => (value, format!("number {}", describe(&parameters))),
+        None => (sample(defaults, rng).unwrap(), format!("参数无效, 使用 {}", describe(defaults))),
+    };
+    GenerateRandom::new(RandomKind::Number, value).with_subtitle(subtitle)
+}
+
+/// 人民币金额, 默认在 0.01..100000 之间, 可用 `100..5000`、`-500..-100` 指定范围 (含两端), `precision 0` 只生成整元
+/// 两端按十进制精确解析并向内取整, 范围内没有该精度的金额时提高精度, 绝对值超过 10 万亿的范围改用默认范围, 副标题说明实际使用的值
+/// 可用 `大写`、`数字` 指定格式, 其余格式作为备选操作, 负数金额的大写以负开头
+fn random_money(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let precision = options.parameters(&["precision", "精度"]).first()
+        .and_then(|w| w.parse::<u32>().ok())
+        .unwrap_or(2)
+        .min(2);
+    // 以分为单位不超过 1e15, 保证大写金额的单位够用
+    const MAX_CENTS: i128 = 1_000_000_000_000_000;
+    let mut subtitle = None;
+    let default = (Decimal::parse("0.01").unwrap(), Decimal::from(100000));
+    let (start, end) = match &options.range {
+        Some((start, end)) => match (Decimal::parse(start).unwrap_or(Decimal::from(0)), Decimal::parse(end).unwrap_or(default.1)) {
+            (start, end) if [start, end].iter().all(|d| (-MAX_CENTS..=MAX_CENTS).contains(&d.scaled(2, false))) => (start, end),
+            _ => {
+                subtitle = Some("金额超出 10 万亿, 使用 0.01..100000".to_string());
+                default
+            }
+        },
+        None => default,
+    };
+    let (start, end) = if start.scaled(2, false) > end.scaled(2, false) { (end, start) } else { (start, end) };
+    // 两端向内取整到精度, 结果以分为单位
+    let inward = |precision: u32| (start.scaled(precision as usize, true) as i64, end.scaled(precision as usize, false) as i64);
+    let (precision, (start, end)) = match (precision..=2).map(|p| (p, inward(p))).find(|(_, (start, end))| start <= end) {
+        Some((actual, range)) if actual == precision => (actual, range),
+        Some((actual, range)) => {
+            subtitle = Some(format!("{} 位小数内没有可选的金额, 使用 {} 位小数", precision, actual));
+            (actual, range)
+        }
+        None => {
+            subtitle = Some("范围内没有整分的金额, 使用最接近的金额".to_string());
+            let nearest = start.scaled(2, false) as i64;
+            (2, (nearest, nearest))
+        }
+    };
+    let cents = rng.gen_range(start..=end) * 10i64.pow(2 - precision);
+
+    let format = |name: &str| {
+        let sign = if cents < 0 { "-" } else { "" };
+        let amount = cents.unsigned_abs();
+        let fraction = match precision {
+            0 => String::new(),
+            1 => format!(".{}", amount % 100 / 10),
+            _ => format!(".{:02}", amount % 100),
+        };
+        match name {
+            "大写" if cents < 0 => format!("负{}", rmb_uppercase(amount)),
+            "大写" => rmb_uppercase(amount),
+            "数字" => format!("{}{}{}", sign, amount / 100, fraction),
+            _ => format!("{}¥{}{}", sign, group_thousands(amount / 100), fraction),
+        }
+    };
+    let value = with_formats(RandomKind::Money, options, &init_money_format(), format);
+    match subtitle {
+        Some(subtitle) => value.with_subtitle(subtitle),
+        None => value,
+    }
+}
+
+/// 每三位加逗号, 例如 12345 -> 12,345
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// 中文大写金额, 例如 1234567 分 -> 壹万贰仟叁佰肆拾伍元陆角柒分
+/// 按四位一节读, 节内和节间的连续 0 只读一个零; 没有角分时以整结尾, 不足一元时省略元
+fn rmb_uppercase(cents: u64) -> String {
+    const DIGITS: [&str; 10] = ["零", "壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖"];
+    const UNITS: [&str; 4] = ["", "拾", "佰", "仟"];
+    const SECTIONS: [&str; 4] = ["", "万", "亿", "万亿"];
+    let (yuan, jiao, fen) = (cents / 100, cents % 100 / 10, cents % 10);
+
+    let mut text = String::new();
+    let mut pending_zero = false;
+    for (section, name) in SECTIONS.iter().enumerate().rev() {
+        let group = yuan / 10000u64.pow(section as u32) % 10000;
+        if group == 0 {
+            pending_zero = !text.is_empty();
+            continue;
+        }
+        if !text.is_empty() && (pending_zero || group < 1000) {
+            text.push_str(DIGITS[0]);
+        }
+        let mut zero_in_group = false;
+        for position in (0..4).rev() {
+            let digit = (group / 10u64.pow(position as u32) % 10) as usize;
+            if digit == 0 {
+                // 节内开头的 0 已在节前处理
+                zero_in_group = group >= 10u64.pow(position as u32 + 1);
+                continue;
+            }
+            if zero_in_group {
+                text.push_str(DIGITS[0]);
+                zero_in_group = false;
+            }
+            text.push_str(DIGITS[digit]);
+            text.push_str(UNITS[position]);
+        }
+        text.push_str(name);
+        pending_zero = false;
+    }
+    if !text.is_empty() {
+        text.push('元');
     }
+    match (jiao, fen) {
+        (0, 0) if text.is_empty() => "零元整".to_string(),
+        (0, 0) => text + "整",
+        (0, fen) if text.is_empty() => format!("{}分", DIGITS[fen as usize]),
+        (0, fen) => format!("{}零{}分", text, DIGITS[fen as usize]),
+        (jiao, 0) => format!("{}{}角", text, DIGITS[jiao as usize]),
+        (jiao, fen) => format!("{}{}角{}分", text, DIGITS[jiao as usize], DIGITS[fen as usize]),
+    }
+}
+
+/// 18 位居民身份证号: 6 位行政区划码 + 8 位出生日期 + 3 位顺序码 + 1 位校验码
+/// 顺序码末位奇数为男性, 偶数为女性; 出生年份可用 `1990..2000` 或 `1990` 指定, 地区可用 `广东`、`深圳` 指定
+fn random_id_card(options: &Options, rng: &mut impl Rng) -> String {
+    let (_, _, district) = random_district(options, rng);
+
+    let today = Local::now().date_naive();
+    let (start_year, end_year) = match (&options.range, options.words.iter().find_map(|w| w.parse::<i32>().ok())) {
+        (Some((start, end)), _) => (start.parse().unwrap_or(1960), end.parse().unwrap_or(today.year())),
+        (None, Some(year)) => (year, year),
+        (None, None) => (1960, today.year() - 18),
+    };
+    let (start_year, end_year) = (start_year.min(end_year).max(1900), start_year.max(end_year).max(1900));
+    let default_start = NaiveDate::from_ymd_opt(1960, 1, 1).unwrap();
+    let start_date = NaiveDate::from_ymd_opt(start_year, 1, 1).unwrap_or(default_start);
+    let end_date = NaiveDate::from_ymd_opt(end_year, 12, 31).unwrap_or(today).min(today);
+    // 出生年份全在将来时没有合法的出生日期, 退回默认范围
+    let (start_date, end_date) = if start_date > today {
+        (default_start, NaiveDate::from_ymd_opt(today.year() - 18, 12, 31).unwrap_or(today))
+    } else {
+        (start_date, end_date)
+    };
+    let days = (end_date - start_date).num_days();
+    let birthday = start_date + Duration::days(rng.gen_range(0..=days));
+
+    let gender = options.gender().unwrap_or(if rng.gen_bool(0.5) { Gender::Male } else { Gender::Female });
+    let gender_digit = match gender {
+        Gender::Male => rng.gen_range(0..5) * 2 + 1,
+        Gender::Female => rng.gen_range(0..5) * 2,
+    };
+    let sequence = format!("{:02}{}", rng.gen_range(0..100), gender_digit);
+
+    let body = format!("{}{}{}", district.code, birthday.format("%Y%m%d"), sequence);
+    format!("{}{}", body, id_card_check_digit(&body))
+}
+
+/// 统一社会信用代码: 登记管理部门码 + 机构类别码 + 6 位行政区划码 + 9 位组织机构代码 + 1 位校验码
+/// 机构类别可用 `企业`、`个体`、`事业单位`、`社会团体` 等指定, 默认为企业
+fn random_uscc(options: &Options, rng: &mut impl Rng) -> String {
+    let (_, _, district) = random_district(options, rng);
+    let org_types = init_uscc_org_type();
+    let (authority, org_type, _) = org_types.iter()
+        .find(|(_, _, name)| options.words.iter().any(|w| name.contains(w.as_str())))
+        .unwrap_or(&org_types[0]);
+
+    // 2015 年后新登记的主体组织机构代码以 MA 开头, 其余多为纯数字
+    let org_body: String = if rng.gen_bool(0.5) {
+        let chars: Vec<char> = USCC_CHARS.chars().collect();
+        let tail: String = (0..6).map(|_| chars[rng.gen_range(0..chars.len())]).collect();
+        format!("MA{}", tail)
+    } else {
+        (0..8).map(|_| rng.gen_range(0..10).to_string()).collect()
+    };
+    let org_code = format!("{}{}", org_body, org_code_check_char(&org_body));
+
+    let body = format!("{}{}{}{}", authority, org_type, district.code, org_code);
+    format!("{}{}", body, uscc_check_char(&body))
+}
 
-    // 添加 165、166、167 的前缀
-    phone_prefix.push("165".to_string());
-    phone_prefix.push("166".to_string());
-    phone_prefix.push("167".to_string());
+/// 统一社会信用代码可用字符, 不含 I、O、S、V、Z
+const USCC_CHARS: &str = "0123456789ABCDEFGHJKLMNPQRTUWXY";
 
-    // 循环生成 170-179 的前缀
-    for i in 0..=9 {
-        let prefix = format!("17{}", i);
-        phone_prefix.push(prefix);
+/// GB 11714 组织机构代码校验码
+fn org_code_check_char(body: &str) -> char {
+    const WEIGHTS: [u32; 8] = [3, 7, 9, 10, 5, 8, 4, 2];
+    let sum: u32 = body.chars()
+        .zip(WEIGHTS.iter())
+        .map(|(c, w)| c.to_digit(36).unwrap_or(0) * w)
+        .sum();
+    match 11 - sum % 11 {
+        10 => 'X',
+        11 => '0',
+        n => char::from_digit(n, 10).unwrap(),
     }
+}
+
+/// GB 32100 统一社会信用代码校验码
+fn uscc_check_char(body: &str) -> char {
+    const WEIGHTS: [u32; 17] = [1, 3, 9, 27, 19, 26, 16, 17, 20, 29, 25, 13, 8, 24, 10, 30, 28];
+    let sum: u32 = body.chars()
+        .zip(WEIGHTS.iter())
+        .map(|(c, w)| USCC_CHARS.find(c).unwrap_or(0) as u32 * w)
+        .sum();
+    USCC_CHARS.chars().nth(((31 - sum % 31) % 31) as usize).unwrap()
+}
 
-    // 循环生成 180-189 的前缀
-    for i in 0..=9 {
-        let prefix = format!("18{}", i);
-        phone_prefix.push(prefix);
+/// 银行卡号: 发卡行 BIN + 随机账号 + Luhn 校验位
+/// 发卡行可用 `icbc`、`工商` 等指定, 卡种可用 `credit`/`信用卡`、`debit`/`借记卡` 指定, 长度为 16-19 位卡号位数
+fn random_bank_card(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let card_type = if options.has(&["credit", "信用卡", "信用"]) {
+        Some("信用卡")
+    } else if options.has(&["debit", "借记卡", "借记"]) {
+        Some("借记卡")
+    } else {
+        None
+    };
+    let bank_words: Vec<&String> = options.words.iter()
+        .filter(|w| init_bank_bin().iter().any(|(code, name, ..)| w == &code || name.contains(w.as_str())))
+        .collect();
+    let bins: Vec<_> = init_bank_bin().into_iter()
+        .filter(|(code, name, ..)| bank_words.is_empty() || bank_words.iter().any(|w| *w == code || name.contains(w.as_str())))
+        .filter(|(.., kind)| card_type.is_none_or(|t| t == kind))
+        .filter(|(_, _, _, length, _)| options.length.is_none_or(|l| *length == 0 || *length == l))
+        .collect();
+    let bins = if bins.is_empty() { init_bank_bin() } else { bins };
+    let (_, bank_name, bin, length, kind) = bins[rng.gen_range(0..bins.len())].clone();
+
+    let length = match (length, options.length) {
+        (0, Some(l)) => l.clamp(16, 19),
+        (0, None) => rng.gen_range(16..=19),
+        (l, _) => l,
+    };
+    let mut body = bin.clone();
+    while body.len() < length - 1 {
+        body.push_str(&rng.gen_range(0..10).to_string());
     }
+    let card = format!("{}{}", body, luhn_check_digit(&body));
+    GenerateRandom::new(RandomKind::BankCard, card).with_subtitle(format!("{} {}", bank_name, kind))
+}
+
+/// Luhn 校验位, 从右向左隔位乘 2
+fn luhn_check_digit(body: &str) -> u32 {
+    let sum: u32 = body.chars().rev()
+        .enumerate()
+        .map(|(i, c)| {
+            let digit = c.to_digit(10).unwrap_or(0);
+            if i % 2 == 0 {
+                let double = digit * 2;
+                if double > 9 { double - 9 } else { double }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+const DIGITS: &str = "0123456789";
+
+/// 车牌字母, 不含易与数字混淆的 I、O
+const PLATE_LETTERS: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ";
 
-    // 添加 190、191、193 的前缀
-    phone_prefix.push("190".to_string());
-    phone_prefix.push("191".to_string());
-    phone_prefix.push("193".to_string());
+/// 机动车号牌: 省份简称 + 发牌机关代号 + 序号
+/// 普通号牌序号 5 位, 最多 2 位字母; 新能源号牌序号 6 位,
+/// 小型车首位为 D(纯电动)/F(非纯电动), 大型车末位为 D/F
+/// 省份可用 `粤`、`广东` 指定, 可用 `ev`/`新能源` 或 `普通` 指定号牌种类
+fn random_plate(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let provinces = init_plate_province();
+    let matched: Vec<_> = provinces.iter()
+        .filter(|(abbr, name, _)| options.words.iter().any(|w| w == abbr || name.starts_with(w.as_str())))
+        .collect();
+    let (abbr, name, city_letters) = if matched.is_empty() {
+        &provinces[rng.gen_range(0..provinces.len())]
+    } else {
+        matched[rng.gen_range(0..matched.len())]
+    };
+    let city = pick_char(rng, city_letters);
+
+    let new_energy = if options.has(&["ev", "nev", "新能源"]) {
+        true
+    } else if options.has(&["普通", "ordinary"]) {
+        false
+    } else {
+        rng.gen_bool(0.3)
+    };
+    let (serial, plate_type): (String, &str) = if !new_energy {
+        let letter_positions: Vec<usize> = (0..rng.gen_range(0..=2)).map(|_| rng.gen_range(0..5)).collect();
+        let serial = (0..5)
+            .map(|i| pick_char(rng, if letter_positions.contains(&i) { PLATE_LETTERS } else { DIGITS }))
+            .collect();
+        (serial, "普通号牌")
+    } else if rng.gen_bool(0.8) {
+        let energy = pick_char(rng, "DF");
+        let second_chars = if rng.gen_bool(0.5) { PLATE_LETTERS } else { DIGITS };
+        let second = pick_char(rng, second_chars);
+        let tail: String = (0..4).map(|_| pick_char(rng, DIGITS)).collect();
+        (format!("{}{}{}", energy, second, tail), "新能源小型车")
+    } else {
+        let head: String = (0..5).map(|_| pick_char(rng, DIGITS)).collect();
+        (format!("{}{}", head, pick_char(rng, "DF")), "新能源大型车")
+    };
+    GenerateRandom::new(RandomKind::Plate, format!("{}{}{}", abbr, city, serial))
+        .with_subtitle(format!("{} {}", name, plate_type))
+}
+
+/// 省 -> 市 -> 区县 -> 街道 -> 门牌号 -> 房间号, 按住修饰键可复制对应的邮政编码
+/// 地区可用 `广东`、`深圳`、`南山` 指定
+fn random_address(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let (province, city, district) = random_district(options, rng);
+    let streets = init_street();
+    let street = &streets[rng.gen_range(0..streets.len())];
+    // 直辖市省、市同名, 只保留一级
+    let city_name = if province.name == city.name { "" } else { city.name };
+    let house = format!("{}号", rng.gen_range(1..1000));
+    let room = format!("{}{:02}室", rng.gen_range(1..34), rng.gen_range(1..7));
+    let room = if rng.gen_bool(0.7) {
+        format!("{}栋{}单元{}", rng.gen_range(1..30), rng.gen_range(1..6), room)
+    } else {
+        room
+    };
+    let address = format!("{}{}{}{}{}{}", province.name, city_name, district.name, street, house, room);
+    GenerateRandom::new(RandomKind::Address, address)
+        .with_alternative("邮政编码".to_string(), district.postal_code.to_string())
+}
+
+fn pick_char(rng: &mut impl Rng, chars: &str) -> char {
+    let chars: Vec<char> = chars.chars().collect();
+    chars[rng.gen_range(0..chars.len())]
+}
 
-    // 添加 195、196、197、198、199 的前缀
-    phone_prefix.push("195".to_string());
-    phone_prefix.push("196".to_string());
-    phone_prefix.push("197".to_string());
-    phone_prefix.push("198".to_string());
-    phone_prefix.push("199".to_string());
-    phone_prefix
+/// 按附加参数中的地名筛选区县, 没有匹配时在全部区县中随机
+fn random_district(options: &Options, rng: &mut impl Rng) -> (&'static region::Province, &'static region::City, &'static region::District) {
+    let districts = options.words.iter()
+        .map(|w| region::find_districts(w))
+        .find(|d| !d.is_empty())
+        .unwrap_or_else(|| region::find_districts(""));
+    districts[rng.gen_range(0..districts.len())]
+}
+
+/// ISO 7064 MOD 11-2 校验码
+fn id_card_check_digit(body: &str) -> char {
+    const WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+    const CHECK_CODES: [char; 11] = ['1', '0', 'X', '9', '8', '7', '6', '5', '4', '3', '2'];
+    let sum: u32 = body.chars()
+        .zip(WEIGHTS.iter())
+        .map(|(c, w)| c.to_digit(10).unwrap_or(0) * w)
+        .sum();
+    CHECK_CODES[(sum % 11) as usize]
+}
+
+/// 手机号段 (运营商代码, 运营商, 号段), 号段按工信部分配, 1349 为卫星电话不在其中
+pub fn init_phone_prefix() -> Vec<(String, String, String)> {
+    let carriers: [(&str, &str, &[&str]); 5] = [
+        ("cm", "中国移动", &[
+            "1340", "1341", "1342", "1343", "1344", "1345", "1346", "1347", "1348",
+            "135", "136", "137", "138", "139", "147", "150", "151", "152", "157",
+            "158", "159", "172", "178", "182", "183", "184", "187", "188", "195",
+            "197", "198",
+        ]),
+        ("cu", "中国联通", &[
+            "130", "131", "132", "145", "155", "156", "166", "175", "176", "185",
+            "186", "196",
+        ]),
+        ("ct", "中国电信", &[
+            "133", "149", "153", "173", "177", "180", "181", "189", "190", "191",
+            "193", "199",
+        ]),
+        ("cbn", "中国广电", &["192"]),
+        ("mvno", "虚拟运营商", &["162", "165", "167", "170", "171"]),
+    ];
+    carriers.iter()
+        .flat_map(|(code, name, prefixes)| prefixes.iter().map(|prefix| (code.to_string(), name.to_string(), prefix.to_string())))
+        .collect()
+}
+
+/// 区号取自城市, 可附加城市名, 号码位数随城市为 7 或 8 位, 首位不为 0 和 1
+/// 可用 `空格`、`国际`、`分机` 指定格式, 其余格式作为备选操作
+fn random_landline(options: &Options, rng: &mut impl Rng) -> GenerateRandom {
+    let (_, city, _) = random_district(options, rng);
+    let subscriber: String = std::iter::once(rng.gen_range(2..10))
+        .chain((1..city.subscriber_length).map(|_| rng.gen_range(0..10)))
+        .map(|i| i.to_string())
+        .collect();
+    let extension = rng.gen_range(100..10000);
+
+    let format = |name: &str| {
+        let (head, tail) = subscriber.split_at(subscriber.len() - 4);
+        match name {
+            "空格" => format!("{}-{} {}", city.area_code, head, tail),
+            "国际" => format!("+86 {} {} {}", &city.area_code[1..], head, tail),
+            "分机" => format!("{}-{} ext {}", city.area_code, subscriber, extension),
+            _ => format!("{}-{}", city.area_code, subscriber),
+        }
+    };
+    with_formats(RandomKind::Landline, options, &init_landline_format(), format)
+        .with_subtitle(format!("landline {}", city.name))
+}
+
+/// UUID 格式及查询关键字, 第一个为默认格式
+fn init_uuid_format() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("标准", &["hyphenated", "标准"]),
+        ("大写", &["upper", "uppercase", "大写"]),
+        ("无横线", &["simple", "nohyphen", "无横线"]),
+        ("大括号", &["braced", "brace", "大括号"]),
+        ("URN", &["urn"]),
+    ]
+}
+
+/// 金额格式及查询关键字, 第一个为默认格式
+fn init_money_format() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("金额", &["yuan", "金额"]),
+        ("大写", &["upper", "uppercase", "chinese", "大写"]),
+        ("数字", &["plain", "数字"]),
+    ]
+}
+
+/// 固定电话格式及查询关键字, 第一个为默认格式
+fn init_landline_format() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("区号", &["plain", "区号"]),
+        ("空格", &["spaced", "space", "空格"]),
+        ("国际", &["e164", "+86", "intl", "国际"]),
+        ("分机", &["ext", "extension", "分机"]),
+    ]
+}
+
+/// 分布名称、查询关键字及默认参数: normal (均值, 标准差)、lognormal (μ, σ)、exponential (λ)、poisson (λ)、zipf (元素个数, 指数)
+pub fn init_distribution() -> Vec<(&'static str, &'static [&'static str], &'static [f64])> {
+    vec![
+        ("normal", &["normal", "正态"], &[0.0, 1.0]),
+        ("lognormal", &["lognormal", "对数正态"], &[0.0, 1.0]),
+        ("exponential", &["exponential", "exp", "指数"], &[1.0]),
+        ("poisson", &["poisson", "泊松"], &[1.0]),
+        ("zipf", &["zipf", "齐夫", "幂律"], &[100.0, 1.0]),
+    ]
+}
+
+/// 手机号格式及查询关键字, 第一个为默认格式
+fn init_phone_format() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("纯数字", &["plain", "纯数字"]),
+        ("国际空格", &["spaced", "space", "空格"]),
+        ("国际", &["e164", "+86", "intl", "国际"]),
+        ("横线", &["dash", "dashed", "横线", "-"]),
+        ("括号", &["paren", "括号", "(+86)"]),
+    ]
+}
+
+/// 运营商代码及查询关键字
+fn init_phone_carrier() -> Vec<(String, Vec<String>)> {
+    let carriers: [(&str, &[&str]); 5] = [
+        ("cm", &["cm", "cmcc", "移动", "中国移动", "yidong", "yd"]),
+        ("cu", &["cu", "unicom", "联通", "中国联通", "liantong", "lt"]),
+        ("ct", &["ct", "telecom", "电信", "中国电信", "dianxin", "dx"]),
+        ("cbn", &["cbn", "broadnet", "广电", "中国广电", "guangdian", "gd"]),
+        ("mvno", &["mvno", "virtual", "虚拟", "虚拟运营商", "xuni"]),
+    ];
+    carriers.iter()
+        .map(|(code, keywords)| (code.to_string(), keywords.iter().map(|k| k.to_string()).collect()))
+        .collect()
 }
 
 fn init_email_server() -> Vec<String> {
-    let mut email_server = Vec::new();
-    email_server.push("qq.com".to_string());
-    email_server.push("163.com".to_string());
-    email_server.push("126.com".to_string());
-    email_server.push("gmail.com".to_string());
-    email_server.push("outlook.com".to_string());
-    email_server.push("yahoo.com".to_string());
-    email_server.push("sina.com".to_string());
-    email_server.push("sohu.com".to_string());
-    email_server.push("aliyun.com".to_string());
-    email_server.push("hotmail.com".to_string());
-    email_server.push("icloud.com".to_string());
-    email_server.push("live.com".to_string());
+    let email_server = vec![
+        "qq.com".to_string(),
+        "163.com".to_string(),
+        "126.com".to_string(),
+        "gmail.com".to_string(),
+        "outlook.com".to_string(),
+        "yahoo.com".to_string(),
+        "sina.com".to_string(),
+        "sohu.com".to_string(),
+        "aliyun.com".to_string(),
+        "hotmail.com".to_string(),
+        "icloud.com".to_string(),
+        "live.com".to_string(),
+    ];
     email_server
 }
 
-fn init_name_prefix() -> Vec<String> {
-    let name_prefix_list = vec!["赵".to_string(), "钱".to_string(), "孙".to_string(), "李".to_string(), "周".to_string(), "吴".to_string(), "郑".to_string(), "王".to_string(), "冯".to_string(), "陈".to_string(), "褚".to_string(), "卫".to_string(), "蒋".to_string(), "沈".to_string(), "韩".to_string(), "杨".to_string(), "朱".to_string(), "秦".to_string(), "尤".to_string(), "许".to_string(),
-                                "何".to_string(), "吕".to_string(), "施".to_string(), "张".to_string(), "孔".to_string(), "曹".to_string(), "严".to_string(), "华".to_string(), "金".to_string(), "魏".to_string(), "陶".to_string(), "姜".to_string(), "戚".to_string(), "谢".to_string(), "邹".to_string(), "喻".to_string(), "柏".to_string(), "水".to_string(), "窦".to_string(), "章".to_string(),
-                                "云".to_string(), "苏".to_string(), "潘".to_string(), "葛".to_string(), "奚".to_string(), "范".to_string(), "彭".to_string(), "郎".to_string(), "鲁".to_string(), "韦".to_string(), "昌".to_string(), "马".to_string(), "苗".to_string(), "凤".to_string(), "花".to_string(), "方".to_string(), "俞".to_string(), "任".to_string(), "袁".to_string(), "柳".to_string(),
-                                "酆".to_string(), "鲍".to_string(), "史".to_string(), "唐".to_string(), "费".to_string(), "廉".to_string(), "岑".to_string(), "薛".to_string(), "雷".to_string(), "贺".to_string(), "倪".to_string(), "汤".to_string(), "滕".to_string(), "殷".to_string(), "罗".to_string(), "毕".to_string(), "郝".to_string(), "邬".to_string(), "安".to_string(), "常".to_string(),
-                                "乐".to_string(), "于".to_string(), "时".to_string(), "傅".to_string(), "皮".to_string(), "卞".to_string(), "齐".to_string(), "康".to_string(), "伍".to_string(), "余".to_string(), "元".to_string(), "卜".to_string(), "顾".to_string(), "孟".to_string(), "平".to_string(), "黄".to_string(), "和".to_string(), "穆".to_string(), "萧".to_string(), "尹".to_string(),
-                                "姚".to_string(), "邵".to_string(), "堪".to_string(), "汪".to_string(), "祁".to_string(), "毛".to_string(), "禹".to_string(), "狄".to_string(), "米".to_string(), "贝".to_string(), "明".to_string(), "臧".to_string(), "计".to_string(), "伏".to_string(), "成".to_string(), "戴".to_string(), "谈".to_string(), "宋".to_string(), "茅".to_string(), "庞".to_string(),
-                                "熊".to_string(), "纪".to_string(), "舒".to_string(), "屈".to_string(), "项".to_string(), "祝".to_string(), "董".to_string(), "梁".to_string()];
-    name_prefix_list
-}
-
-fn init_name_suffix() -> Vec<String> {
-    let name_suffix_list = vec!["的".to_string(), "一".to_string(), "是".to_string(), "了".to_string(), "我".to_string(), "不".to_string(), "人".to_string(), "在".to_string(), "他".to_string(), "有".to_string(), "这".to_string(), "个".to_string(), "上".to_string(), "们".to_string(), "来".to_string(), "到".to_string(), "时".to_string(), "大".to_string(), "地".to_string(), "为".to_string(),
-                                "子".to_string(), "中".to_string(), "你".to_string(), "说".to_string(), "生".to_string(), "国".to_string(), "年".to_string(), "着".to_string(), "就".to_string(), "那".to_string(), "和".to_string(), "要".to_string(), "她".to_string(), "出".to_string(), "也".to_string(), "得".to_string(), "里".to_string(), "后".to_string(), "自".to_string(), "以".to_string(),
-                                "会".to_string(), "家".to_string(), "可".to_string(), "下".to_string(), "而".to_string(), "过".to_string(), "天".to_string(), "去".to_string(), "能".to_string(), "对".to_string(), "小".to_string(), "多".to_string(), "然".to_string(), "于".to_string(), "心".to_string(), "学".to_string(), "么".to_string(), "之".to_string(), "都".to_string(), "好".to_string(),
-                                "看".to_string(), "起".to_string(), "发".to_string(), "当".to_string(), "没".to_string(), "成".to_string(), "只".to_string(), "如".to_string(), "事".to_string(), "把".to_string(), "还".to_string(), "用".to_string(), "第".to_string(), "样".to_string(), "道".to_string(), "想".to_string(), "作".to_string(), "种".to_string(), "开".to_string(), "美".to_string(),
-                                "总".to_string(), "从".to_string(), "无".to_string(), "情".to_string(), "己".to_string(), "面".to_string(), "最".to_string(), "女".to_string(), "但".to_string(), "现".to_string(), "前".to_string(), "些".to_string(), "所".to_string(), "同".to_string(), "日".to_string(), "手".to_string(), "又".to_string(), "行".to_string(), "意".to_string(), "动".to_string(),
-                                "方".to_string(), "期".to_string(), "它".to_string(), "头".to_string(), "经".to_string(), "长".to_string(), "儿".to_string(), "回".to_string(), "位".to_string(), "分".to_string(), "爱".to_string(), "老".to_string(), "因".to_string(), "很".to_string(), "给".to_string(), "名".to_string(), "法".to_string(), "间".to_string(), "斯".to_string(), "知".to_string(),
-                                "世".to_string(), "什".to_string(), "两".to_string(), "次".to_string(), "使".to_string(), "身".to_string(), "者".to_string(), "被".to_string(), "高".to_string(), "已".to_string(), "亲".to_string(), "其".to_string(), "进".to_string(), "此".to_string(), "话".to_string(), "常".to_string(), "与".to_string(), "活".to_string(), "正".to_string(), "感".to_string(),
-                                "见".to_string(), "明".to_string(), "问".to_string(), "力".to_string(), "理".to_string(), "尔".to_string(), "点".to_string(), "文".to_string(), "几".to_string(), "定".to_string(), "本".to_string(), "公".to_string(), "特".to_string(), "做".to_string(), "外".to_string(), "孩".to_string(), "相".to_string(), "西".to_string(), "果".to_string(), "走".to_string(),
-                                "将".to_string(), "月".to_string(), "十".to_string(), "实".to_string(), "向".to_string(), "声".to_string(), "车".to_string(), "全".to_string(), "信".to_string(), "重".to_string(), "三".to_string(), "机".to_string(), "工".to_string(), "物".to_string(), "气".to_string(), "每".to_string(), "并".to_string(), "别".to_string(), "真".to_string(), "打".to_string(),
-                                "太".to_string(), "新".to_string(), "比".to_string(), "才".to_string(), "便".to_string(), "夫".to_string(), "再".to_string(), "书".to_string(), "部".to_string(), "水".to_string(), "像".to_string(), "眼".to_string(), "等".to_string(), "体".to_string(), "却".to_string(), "加".to_string(), "电".to_string(), "主".to_string(), "界".to_string(), "门".to_string(),
-                                "利".to_string(), "海".to_string(), "受".to_string(), "听".to_string(), "表".to_string(), "德".to_string(), "少".to_string(), "克".to_string(), "代".to_string(), "员".to_string(), "许".to_string(), "稜".to_string(), "先".to_string(), "口".to_string(), "由".to_string(), "死".to_string(), "安".to_string(), "写".to_string(), "性".to_string(), "马".to_string(),
-                                "光".to_string(), "白".to_string(), "或".to_string(), "住".to_string(), "难".to_string(), "望".to_string(), "教".to_string(), "命".to_string(), "花".to_string(), "结".to_string(), "乐".to_string(), "色".to_string(), "更".to_string(), "拉".to_string(), "东".to_string(), "神".to_string(), "记".to_string(), "处".to_string(), "让".to_string(), "母".to_string(),
-                                "父".to_string(), "应".to_string(), "直".to_string(), "字".to_string(), "场".to_string(), "平".to_string(), "报".to_string(), "友".to_string(), "关".to_string(), "放".to_string(), "至".to_string(), "张".to_string(), "认".to_string(), "接".to_string(), "告".to_string(), "入".to_string(), "笑".to_string(), "内".to_string(), "英".to_string(), "军".to_string(),
-                                "候".to_string(), "民".to_string(), "岁".to_string(), "往".to_string(), "何".to_string(), "度".to_string(), "山".to_string(), "觉".to_string(), "路".to_string(), "带".to_string(), "万".to_string(), "男".to_string(), "边".to_string(), "风".to_string(), "解".to_string(), "叫".to_string(), "任".to_string(), "金".to_string(), "快".to_string(), "原".to_string(),
-                                "吃".to_string(), "妈".to_string(), "变".to_string(), "通".to_string(), "师".to_string(), "立".to_string(), "象".to_string(), "数".to_string(), "四".to_string(), "失".to_string(), "满".to_string(), "战".to_string(), "远".to_string(), "格".to_string(), "士".to_string(), "音".to_string(), "轻".to_string(), "目".to_string(), "条".to_string(), "呢".to_string(),
-                                "病".to_string(), "始".to_string(), "达".to_string(), "深".to_string(), "完".to_string(), "今".to_string(), "提".to_string(), "求".to_string(), "清".to_string(), "王".to_string(), "化".to_string(), "空".to_string(), "业".to_string(), "思".to_string(), "切".to_string(), "怎".to_string(), "非".to_string(), "找".to_string(), "片".to_string(), "罗".to_string(),
-                                "钱".to_string(), "紶".to_string(), "吗".to_string(), "语".to_string(), "元".to_string(), "喜".to_string(), "曾".to_string(), "离".to_string(), "飞".to_string(), "科".to_string(), "言".to_string(), "干".to_string(), "流".to_string(), "欢".to_string(), "约".to_string(), "各".to_string(), "即".to_string(), "指".to_string(), "合".to_string(), "反".to_string(),
-                                "题".to_string(), "必".to_string(), "该".to_string(), "论".to_string(), "交".to_string(), "终".to_string(), "林".to_string(), "请".to_string(), "医".to_string(), "晚".to_string(), "制".to_string(), "球".to_string(), "决".to_string(), "窢".to_string(), "传".to_string(), "画".to_string(), "保".to_string(), "读".to_string(), "运".to_string(), "及".to_string(),
-                                "则".to_string(), "房".to_string(), "早".to_string(), "院".to_string(), "量".to_string(), "苦".to_string(), "火".to_string(), "布".to_string(), "品".to_string(), "近".to_string(), "坐".to_string(), "产".to_string(), "答".to_string(), "星".to_string(), "精".to_string(), "视".to_string(), "五".to_string(), "连".to_string(), "司".to_string(), "巴".to_string(),
-                                "奇".to_string(), "管".to_string(), "类".to_string(), "未".to_string(), "朋".to_string(), "且".to_string(), "婚".to_string(), "台".to_string(), "夜".to_string(), "青".to_string(), "北".to_string(), "队".to_string(), "久".to_string(), "乎".to_string(), "越".to_string(), "观".to_string(), "落".to_string(), "尽".to_string(), "形".to_string(), "影".to_string(),
-                                "红".to_string(), "爸".to_string(), "百".to_string(), "令".to_string(), "周".to_string(), "吧".to_string(), "识".to_string(), "步".to_string(), "希".to_string(), "亚".to_string(), "术".to_string(), "留".to_string(), "市".to_string(), "半".to_string(), "热".to_string(), "送".to_string(), "兴".to_string(), "造".to_string(), "谈".to_string(), "容".to_string(),
-                                "极".to_string(), "随".to_string(), "演".to_string(), "收".to_string(), "首".to_string(), "根".to_string(), "讲".to_string(), "整".to_string(), "式".to_string(), "取".to_string(), "照".to_string(), "办".to_string(), "强".to_string(), "石".to_string(), "古".to_string(), "华".to_string(), "諣".to_string(), "拿".to_string(), "计".to_string(), "您".to_string(),
-                                "装".to_string(), "似".to_string(), "足".to_string(), "双".to_string(), "妻".to_string(), "尼".to_string(), "转".to_string(), "诉".to_string(), "米".to_string(), "称".to_string(), "丽".to_string(), "客".to_string(), "南".to_string(), "领".to_string(), "节".to_string(), "衣".to_string(), "站".to_string(), "黑".to_string(), "刻".to_string(), "统".to_string(),
-                                "断".to_string(), "福".to_string(), "城".to_string(), "故".to_string(), "历".to_string(), "惊".to_string(), "脸".to_string(), "选".to_string(), "包".to_string(), "紧".to_string(), "争".to_string(), "另".to_string(), "建".to_string(), "维".to_string(), "绝".to_string(), "树".to_string(), "系".to_string(), "伤".to_string(), "示".to_string(), "愿".to_string(),
-                                "持".to_string(), "千".to_string(), "史".to_string(), "谁".to_string(), "准".to_string(), "联".to_string(), "妇".to_string(), "纪".to_string(), "基".to_string(), "买".to_string(), "志".to_string(), "静".to_string(), "阿".to_string(), "诗".to_string(), "独".to_string(), "复".to_string(), "痛".to_string(), "消".to_string(), "社".to_string(), "算".to_string(),
-                                "义".to_string(), "竟".to_string(), "确".to_string(), "酒".to_string(), "需".to_string(), "单".to_string(), "治".to_string(), "卡".to_string(), "幸".to_string(), "兰".to_string(), "念".to_string(), "举".to_string(), "仅".to_string(), "钟".to_string(), "怕".to_string(), "共".to_string(), "毛".to_string(), "句".to_string(), "息".to_string(), "功".to_string(),
-                                "官".to_string(), "待".to_string(), "究".to_string(), "跟".to_string(), "穿".to_string(), "室".to_string(), "易".to_string(), "游".to_string(), "程".to_string(), "号".to_string(), "居".to_string(), "考".to_string(), "突".to_string(), "皮".to_string(), "哪".to_string(), "费".to_string(), "倒".to_string(), "价".to_string(), "图".to_string(), "具".to_string(),
-                                "刚".to_string(), "脑".to_string(), "永".to_string(), "歌".to_string(), "响".to_string(), "商".to_string(), "礼".to_string(), "细".to_string(), "专".to_string(), "黄".to_string(), "块".to_string(), "脚".to_string(), "味".to_string(), "灵".to_string(), "改".to_string(), "据".to_string(), "般".to_string(), "破".to_string(), "引".to_string(), "食".to_string(),
-                                "仍".to_string(), "存".to_string(), "众".to_string(), "注".to_string(), "笔".to_string(), "甚".to_string(), "某".to_string(), "沉".to_string(), "血".to_string(), "备".to_string(), "习".to_string(), "校".to_string(), "默".to_string(), "务".to_string(), "土".to_string(), "微".to_string(), "娘".to_string(), "须".to_string(), "试".to_string(), "怀".to_string(),
-                                "料".to_string(), "调".to_string(), "广".to_string(), "蜖".to_string(), "苏".to_string(), "显".to_string(), "赛".to_string(), "查".to_string(), "密".to_string(), "议".to_string(), "底".to_string(), "列".to_string(), "富".to_string(), "梦".to_string(), "错".to_string(), "座".to_string(), "参".to_string(), "八".to_string(), "除".to_string(), "跑".to_string(),
-                                "亮".to_string(), "假".to_string(), "印".to_string(), "设".to_string(), "线".to_string(), "温".to_string(), "虽".to_string(), "掉".to_string(), "京".to_string(), "初".to_string(), "养".to_string(), "香".to_string(), "停".to_string(), "际".to_string(), "致".to_string(), "阳".to_string(), "纸".to_string(), "李".to_string(), "纳".to_string(), "验".to_string(),
-                                "助".to_string(), "激".to_string(), "够".to_string(), "严".to_string(), "证".to_string(), "帝".to_string(), "饭".to_string(), "忘".to_string(), "趣".to_string(), "支".to_string(), "春".to_string(), "集".to_string(), "丈".to_string(), "木".to_string(), "研".to_string(), "班".to_string(), "普".to_string(), "导".to_string(), "顿".to_string(), "睡".to_string(),
-                                "展".to_string(), "跳".to_string(), "获".to_string(), "艺".to_string(), "六".to_string(), "波".to_string(), "察".to_string(), "群".to_string(), "皇".to_string(), "段".to_string(), "急".to_string(), "庭".to_string(), "创".to_string(), "区".to_string(), "奥".to_string(), "器".to_string(), "谢".to_string(), "弟".to_string(), "店".to_string(), "否".to_string(),
-                                "害".to_string(), "草".to_string(), "排".to_string(), "背".to_string(), "止".to_string(), "组".to_string(), "州".to_string(), "朝".to_string(), "封".to_string(), "睛".to_string(), "板".to_string(), "角".to_string(), "况".to_string(), "曲".to_string(), "馆".to_string(), "育".to_string(), "忙".to_string(), "质".to_string(), "河".to_string(), "续".to_string(),
-                                "哥".to_string(), "呼".to_string(), "若".to_string(), "推".to_string(), "境".to_string(), "遇".to_string(), "雨".to_string(), "标".to_string(), "姐".to_string(), "充".to_string(), "围".to_string(), "案".to_string(), "伦".to_string(), "护".to_string(), "冷".to_string(), "警".to_string(), "贝".to_string(), "著".to_string(), "雪".to_string(), "索".to_string(),
-                                "剧".to_string(), "啊".to_string(), "船".to_string(), "险".to_string(), "烟".to_string(), "依".to_string(), "斗".to_string(), "值".to_string(), "帮".to_string(), "汉".to_string(), "慢".to_string(), "佛".to_string(), "肯".to_string(), "闻".to_string(), "唱".to_string(), "沙".to_string(), "局".to_string(), "伯".to_string(), "族".to_string(), "低".to_string(),
-                                "玩".to_string(), "资".to_string(), "屋".to_string(), "击".to_string(), "速".to_string(), "顾".to_string(), "泪".to_string(), "洲".to_string(), "团".to_string(), "圣".to_string(), "旁".to_string(), "堂".to_string(), "兵".to_string(), "七".to_string(), "露".to_string(), "园".to_string(), "牛".to_string(), "哭".to_string(), "旅".to_string(), "街".to_string(),
-                                "劳".to_string(), "型".to_string(), "烈".to_string(), "姑".to_string(), "陈".to_string(), "莫".to_string(), "鱼".to_string(), "异".to_string(), "抱".to_string(), "宝".to_string(), "权".to_string(), "鲁".to_string(), "简".to_string(), "态".to_string(), "级".to_string(), "票".to_string(), "怪".to_string(), "寻".to_string(), "杀".to_string(), "律".to_string(),
-                                "胜".to_string(), "份".to_string(), "汽".to_string(), "右".to_string(), "洋".to_string(), "范".to_string(), "床".to_string(), "舞".to_string(), "秘".to_string(), "午".to_string(), "登".to_string(), "楼".to_string(), "贵".to_string(), "吸".to_string(), "责".to_string(), "例".to_string(), "追".to_string(), "较".to_string(), "职".to_string(), "属".to_string(),
-                                "渐".to_string(), "左".to_string(), "录".to_string(), "丝".to_string(), "牙".to_string(), "党".to_string(), "继".to_string(), "托".to_string(), "赶".to_string(), "章".to_string(), "智".to_string(), "冲".to_string(), "叶".to_string(), "胡".to_string(), "吉".to_string(), "卖".to_string(), "坚".to_string(), "喝".to_string(), "肉".to_string(), "遗".to_string(),
-                                "救".to_string(), "修".to_string(), "松".to_string(), "临".to_string(), "藏".to_string(), "担".to_string(), "戏".to_string(), "善".to_string(), "卫".to_string(), "药".to_string(), "悲".to_string(), "敢".to_string(), "靠".to_string(), "伊".to_string(), "村".to_string(), "戴".to_string(), "词".to_string(), "森".to_string(), "耳".to_string(), "差".to_string(),
-                                "短".to_string(), "祖".to_string(), "云".to_string(), "规".to_string(), "窗".to_string(), "散".to_string(), "迷".to_string(), "油".to_string(), "旧".to_string(), "适".to_string(), "乡".to_string(), "架".to_string(), "恩".to_string(), "投".to_string(), "弹".to_string(), "铁".to_string(), "博".to_string(), "雷".to_string(), "府".to_string(), "压".to_string(),
-                                "超".to_string(), "负".to_string(), "勒".to_string(), "杂".to_string(), "醒".to_string(), "洗".to_string(), "采".to_string(), "毫".to_string(), "嘴".to_string(), "毕".to_string(), "九".to_string(), "冰".to_string(), "既".to_string(), "状".to_string(), "乱".to_string(), "景".to_string(), "席".to_string(), "珍".to_string(), "童".to_string(), "顶".to_string(),
-                                "派".to_string(), "素".to_string(), "脱".to_string(), "农".to_string(), "疑".to_string(), "练".to_string(), "野".to_string(), "按".to_string(), "犯".to_string(), "拍".to_string(), "征".to_string(), "坏".to_string(), "骨".to_string(), "余".to_string(), "承".to_string(), "置".to_string(), "臓".to_string(), "彩".to_string(), "灯".to_string(), "巨".to_string(),
-                                "琴".to_string(), "免".to_string(), "环".to_string(), "姆".to_string(), "暗".to_string(), "换".to_string(), "技".to_string(), "翻".to_string(), "束".to_string(), "增".to_string(), "忍".to_string(), "餐".to_string(), "洛".to_string(), "塞".to_string(), "缺".to_string(), "忆".to_string(), "判".to_string(), "欧".to_string(), "层".to_string(), "付".to_string(),
-                                "阵".to_string(), "玛".to_string(), "批".to_string(), "岛".to_string(), "项".to_string(), "狗".to_string(), "休".to_string(), "懂".to_string(), "武".to_string(), "革".to_string(), "良".to_string(), "恶".to_string(), "恋".to_string(), "委".to_string(), "拥".to_string(), "娜".to_string(), "妙".to_string(), "探".to_string(), "呀".to_string(), "营".to_string(),
-                                "退".to_string(), "摇".to_string(), "弄".to_string(), "桌".to_string(), "熟".to_string(), "诺".to_string(), "宣".to_string(), "银".to_string(), "势".to_string(), "奖".to_string(), "宫".to_string(), "忽".to_string(), "套".to_string(), "康".to_string(), "供".to_string(), "优".to_string(), "课".to_string(), "鸟".to_string(), "喊".to_string(), "降".to_string(),
-                                "夏".to_string(), "困".to_string(), "刘".to_string(), "罪".to_string(), "亡".to_string(), "鞋".to_string(), "健".to_string(), "模".to_string(), "败".to_string(), "伴".to_string(), "守".to_string(), "挥".to_string(), "鲜".to_string(), "财".to_string(), "孤".to_string(), "枪".to_string(), "禁".to_string(), "恐".to_string(), "伙".to_string(), "杰".to_string(),
-                                "迹".to_string(), "妹".to_string(), "藸".to_string(), "遍".to_string(), "盖".to_string(), "副".to_string(), "坦".to_string(), "牌".to_string(), "江".to_string(), "顺".to_string(), "秋".to_string(), "萨".to_string(), "菜".to_string(), "划".to_string(), "授".to_string(), "归".to_string(), "浪".to_string(), "听".to_string(), "凡".to_string(), "预".to_string(),
-                                "奶".to_string(), "雄".to_string(), "升".to_string(), "碃".to_string(), "编".to_string(), "典".to_string(), "袋".to_string(), "莱".to_string(), "含".to_string(), "盛".to_string(), "济".to_string(), "蒙".to_string(), "棋".to_string(), "端".to_string(), "腿".to_string(), "招".to_string(), "释".to_string(), "介".to_string(), "烧".to_string(), "误".to_string(),
-                                "乾".to_string(), "坤".to_string()];
-    name_suffix_list
-}
\ No newline at end of file
+/// RFC 2606 / RFC 6761 保留的域名, 不会投递到真实邮箱
+fn init_safe_email_server() -> Vec<String> {
+    vec![
+        "example.com".to_string(),
+        "example.org".to_string(),
+        "example.net".to_string(),
+        "mail.test".to_string(),
+        "demo.test".to_string(),
+        "company.test".to_string(),
+    ]
+}
+
+/// 自定义域名, 取自 Alfred 工作流变量 `email_domains` (逗号或空白分隔),
+/// 以及工作流数据目录下的 `email_domains.txt` (每行一个, # 开头为注释)
+fn init_custom_email_server() -> Vec<String> {
+    let mut domains: Vec<String> = std::env::var("email_domains")
+        .unwrap_or_default()
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|d| d.trim().trim_start_matches('@').to_string())
+        .filter(|d| !d.is_empty())
+        .collect();
+    if let Ok(dir) = std::env::var("alfred_workflow_data") {
+        let path = std::path::Path::new(&dir).join("email_domains.txt");
+        if let Ok(content) = std::fs::read_to_string(path) {
+            domains.extend(content.lines()
+                .map(|line| line.trim().trim_start_matches('@'))
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(|line| line.to_string()));
+        }
+    }
+    domains
+}
+
+/// (银行代码, 银行名称, BIN, 卡号长度, 卡种), 长度为 0 表示 16-19 位随机
+fn init_bank_bin() -> Vec<(String, String, String, usize, String)> {
+    let bins = [
+        ("icbc", "中国工商银行", "622202", 19, "借记卡"),
+        ("icbc", "中国工商银行", "621226", 19, "借记卡"),
+        ("icbc", "中国工商银行", "427018", 16, "信用卡"),
+        ("icbc", "中国工商银行", "427020", 16, "信用卡"),
+        ("ccb", "中国建设银行", "621700", 19, "借记卡"),
+        ("ccb", "中国建设银行", "436742", 19, "借记卡"),
+        ("ccb", "中国建设银行", "622700", 19, "借记卡"),
+        ("ccb", "中国建设银行", "436728", 16, "信用卡"),
+        ("abc", "中国农业银行", "622848", 19, "借记卡"),
+        ("abc", "中国农业银行", "622845", 19, "借记卡"),
+        ("abc", "中国农业银行", "622836", 16, "信用卡"),
+        ("boc", "中国银行", "621661", 19, "借记卡"),
+        ("boc", "中国银行", "601382", 19, "借记卡"),
+        ("boc", "中国银行", "456351", 19, "借记卡"),
+        ("boc", "中国银行", "438088", 16, "信用卡"),
+        ("cmb", "招商银行", "622588", 16, "借记卡"),
+        ("cmb", "招商银行", "621483", 16, "借记卡"),
+        ("cmb", "招商银行", "439225", 16, "信用卡"),
+        ("cmb", "招商银行", "622575", 16, "信用卡"),
+        ("unionpay", "中国银联", "62", 0, "借记卡"),
+    ];
+    bins.iter()
+        .map(|(code, name, bin, length, kind)| (code.to_string(), name.to_string(), bin.to_string(), *length, kind.to_string()))
+        .collect()
+}
+
+/// 各地普遍存在的街道名称
+fn init_street() -> Vec<String> {
+    let streets = [
+        "人民路", "解放路", "中山路", "建设路", "和平路", "新华路", "胜利路", "文化路", "光明路", "长江路",
+        "黄河路", "青年路", "朝阳路", "东风路", "友谊路", "学府路", "科技路", "滨江路", "环城路", "北京路",
+        "南京路", "幸福路", "民主路", "红旗路", "花园路", "建国路", "复兴路", "振兴路", "工业大道", "迎宾大道",
+        "世纪大道", "府前街", "文昌街", "中心街", "新街口", "东大街", "西大街", "南大街", "北大街", "桂花巷",
+    ];
+    streets.iter().map(|s| s.to_string()).collect()
+}
+
+/// (省份简称, 省份名称, 发牌机关代号)
+fn init_plate_province() -> Vec<(String, String, String)> {
+    let provinces = [
+        ("京", "北京", "ABCEFGHJKLMNPQY"),
+        ("津", "天津", "ABCDEFGHJKLMNQR"),
+        ("沪", "上海", "ABCDEFGHJKLMN"),
+        ("渝", "重庆", "ABCDFGH"),
+        ("冀", "河北", "ABCDEFGHJRT"),
+        ("晋", "山西", "ABCDEFHJKLM"),
+        ("蒙", "内蒙古", "ABCDEFGHJKLM"),
+        ("辽", "辽宁", "ABCDEFGHJKLMNP"),
+        ("吉", "吉林", "ABCDEFGHJK"),
+        ("黑", "黑龙江", "ABCDEFGHJKLMNP"),
+        ("苏", "江苏", "ABCDEFGHJKLMN"),
+        ("浙", "浙江", "ABCDEFGHJKL"),
+        ("皖", "安徽", "ABCDEFGHJKLMNPRS"),
+        ("闽", "福建", "ABCDEFGHJK"),
+        ("赣", "江西", "ABCDEFGHJKLM"),
+        ("鲁", "山东", "ABCDEFGHJKLMNPQRSUV"),
+        ("豫", "河南", "ABCDEFGHJKLMNPQRSU"),
+        ("鄂", "湖北", "ABCDEFGHJKLMNPQRS"),
+        ("湘", "湖南", "ABCDEFGHJKLMNU"),
+        ("粤", "广东", "ABCDEFGHJKLMNPQRSTUVWXYZ"),
+        ("桂", "广西", "ABCDEFGHJKLMNPR"),
+        ("琼", "海南", "ABCDE"),
+        ("川", "四川", "ABCDEFHJKLMQRSTUVWXYZ"),
+        ("贵", "贵州", "ABCDEFGHJ"),
+        ("云", "云南", "ACDEFGHJKLMNPQRS"),
+        ("藏", "西藏", "ABCDEFGHJ"),
+        ("陕", "陕西", "ABCDEFGHJKV"),
+        ("甘", "甘肃", "ABCDEFGHJKLMNP"),
+        ("青", "青海", "ABCDEFGH"),
+        ("宁", "宁夏", "ABCDE"),
+        ("新", "新疆", "ABCDEFGHJKLMNPQR"),
+    ];
+    provinces.iter()
+        .map(|(abbr, name, letters)| (abbr.to_string(), name.to_string(), letters.to_string()))
+        .collect()
+}
+
+/// (登记管理部门码, 机构类别码, 类别名称)
+fn init_uscc_org_type() -> Vec<(char, char, String)> {
+    vec![
+        ('9', '1', "企业".to_string()),
+        ('9', '2', "个体工商户".to_string()),
+        ('9', '3', "农民专业合作社".to_string()),
+        ('1', '1', "机关".to_string()),
+        ('1', '2', "事业单位".to_string()),
+        ('5', '1', "社会团体".to_string()),
+        ('5', '2', "民办非企业单位".to_string()),
+        ('5', '3', "基金会".to_string()),
+        ('Y', '1', "其他".to_string()),
+    ]
+}
+
+/// 姓氏及权重, 权重为人口占比 (单位十万分之一), 未列出人口占比的单姓记为 10, 复姓记为 1
+fn init_name_prefix() -> Vec<(String, u32)> {
+    let mut surnames: Vec<(String, u32)> = SINGLE_SURNAMES.concat().chars()
+        .map(|c| (c.to_string(), 10))
+        .chain(COMPOUND_SURNAMES.iter().map(|s| (s.to_string(), 1)))
+        .collect();
+    for (surname, weight) in SURNAME_WEIGHTS {
+        match surnames.iter_mut().find(|(s, _)| s == surname) {
+            Some(entry) => entry.1 = *weight,
+            None => surnames.push((surname.to_string(), *weight)),
+        }
+    }
+    surnames
+}
+
+/// 百家姓单姓, 按原文顺序
+const SINGLE_SURNAMES: &[&str] = &[
+    "赵钱孙李周吴郑王冯陈褚卫蒋沈韩杨朱秦尤许何吕施张孔曹严华金魏陶姜戚谢邹喻柏水窦章",
+    "云苏潘葛奚范彭郎鲁韦昌马苗凤花方俞任袁柳酆鲍史唐费廉岑薛雷贺倪汤滕殷罗毕郝邬安常",
+    "乐于时傅皮卞齐康伍余元卜顾孟平黄和穆萧尹姚邵湛汪祁毛禹狄米贝明臧计伏成戴谈宋茅庞",
+    "熊纪舒屈项祝董梁杜阮蓝闵席季麻强贾路娄危江童颜郭梅盛林刁钟徐邱骆高夏蔡田樊胡凌霍",
+    "虞万支柯昝管卢莫经房裘缪干解应宗丁宣贲邓郁单杭洪包诸左石崔吉钮龚程嵇邢滑裴陆荣翁",
+    "荀羊於惠甄曲家封芮羿储靳汲邴糜松井段富巫乌焦巴弓牧隗山谷车侯宓蓬全郗班仰秋仲伊宫",
+    "宁仇栾暴甘钭厉戎祖武符刘景詹束龙叶幸司韶郜黎蓟薄印宿白怀蒲邰从鄂索咸籍赖卓蔺屠蒙",
+    "池乔阴鬱胥能苍双闻莘党翟谭贡劳逄姬申扶堵冉宰郦雍卻璩桑桂濮牛寿通边扈燕冀郏浦尚农",
+    "温别庄晏柴瞿阎充慕连茹习宦艾鱼容向古易慎戈廖庾终暨居衡步都耿满弘匡国文寇广禄阙东",
+    "欧殳沃利蔚越夔隆师巩厍聂晁勾敖融冷訾辛阚那简饶空曾毋沙乜养鞠须丰巢关蒯相查后荆红",
+    "游竺权逯盖益桓公仉督晋楚闫法汝鄢涂钦岳帅缑亢况郈佘佴墨哈谯笪年爱阳佟言福",
+];
+
+/// 百家姓复姓
+const COMPOUND_SURNAMES: &[&str] = &[
+    "万俟", "司马", "上官", "欧阳", "夏侯", "诸葛", "闻人", "东方", "赫连", "皇甫", "尉迟", "公羊",
+    "澹台", "公冶", "宗政", "濮阳", "淳于", "单于", "太叔", "申屠", "公孙", "仲孙", "轩辕", "令狐",
+    "钟离", "宇文", "长孙", "慕容", "鲜于", "闾丘", "司徒", "司空", "亓官", "司寇", "子车", "颛孙",
+    "端木", "巫马", "公西", "漆雕", "乐正", "壤驷", "公良", "拓跋", "夹谷", "宰父", "谷梁", "段干",
+    "百里", "东郭", "南门", "呼延", "归海", "羊舌", "微生", "梁丘", "左丘", "东门", "西门", "商牟",
+    "伯赏", "南宫", "有琴", "第五",
+];
+
+/// 常见姓氏的人口占比, 单位十万分之一; 肖不在百家姓中但位列常见姓氏前三十
+const SURNAME_WEIGHTS: &[(&str, u32)] = &[
+    ("王", 7100), ("李", 7000), ("张", 6600), ("刘", 5400), ("陈", 4600), ("杨", 3100), ("黄", 2400), ("赵", 2200), ("吴", 2000), ("周", 1900),
+    ("徐", 1500), ("孙", 1500), ("马", 1300), ("朱", 1300), ("胡", 1300), ("郭", 1200), ("何", 1200), ("林", 1100), ("高", 1100), ("罗", 1100),
+    ("郑", 1000), ("梁", 900), ("谢", 800), ("宋", 800), ("唐", 800), ("许", 700), ("韩", 700), ("邓", 700), ("冯", 700), ("曹", 700),
+    ("彭", 600), ("曾", 600), ("肖", 600), ("田", 500), ("董", 500), ("潘", 500), ("袁", 500), ("蔡", 500), ("蒋", 500), ("余", 500),
+    ("于", 500), ("杜", 500), ("叶", 400), ("程", 400), ("魏", 400), ("苏", 400), ("吕", 400), ("丁", 400), ("任", 400), ("卢", 400),
+    ("姚", 400), ("沈", 400), ("钟", 400), ("姜", 300), ("崔", 300), ("谭", 300), ("陆", 300), ("范", 300), ("汪", 300), ("廖", 300),
+    ("石", 300), ("金", 300), ("韦", 300), ("贾", 300), ("夏", 300), ("方", 300), ("邹", 200), ("熊", 200), ("白", 200), ("孟", 200),
+    ("秦", 200), ("邱", 200), ("侯", 200), ("江", 200), ("尹", 200), ("薛", 200), ("闫", 200), ("段", 200), ("雷", 200), ("龙", 200),
+    ("黎", 200), ("史", 200), ("陶", 200), ("贺", 200), ("毛", 200), ("郝", 200), ("顾", 200), ("龚", 200), ("邵", 200), ("万", 200),
+    ("武", 200), ("钱", 200), ("戴", 200), ("严", 200), ("莫", 100), ("孔", 100), ("向", 100), ("常", 100), ("汤", 100), ("康", 100),
+    ("易", 100), ("乔", 100), ("赖", 100), ("文", 100),
+    ("欧阳", 50), ("上官", 5), ("司马", 5), ("诸葛", 5), ("慕容", 5), ("司徒", 5), ("令狐", 5), ("皇甫", 5), ("东方", 5), ("尉迟", 5),
+    ("公孙", 5), ("端木", 5),
+];
+
+/// 常见名字用字, gender 为 None 时返回男女通用的字
+fn init_given_name_char(gender: Option<Gender>) -> Vec<String> {
+    let chars = match gender {
+        Some(Gender::Male) => "伟强磊军勇杰涛斌超明刚平辉鹏飞鑫波宇浩凯健俊帆帅旭龙阳锋峰博毅彬昊轩睿哲航铭泽皓翔成建国志永海东振兴德豪力亮松栋瑞晖诚",
+        Some(Gender::Female) => "芳娜敏静丽艳娟霞秀玲燕萍红梅琳雪颖倩婷慧莉洁琴蓉媛薇怡佳欣悦瑶琪涵诗梦雨露月彤萱妍璐菲婉晴蕾菁岚淑珊婧馨茜钰曼莹",
+        None => "文晨宁安林清思子一嘉乐可然心言逸云天新星远若初辰",
+    };
+    chars.chars().map(|c| c.to_string()).collect()
+}
+
+/// 常见的双字名, gender 为 None 时返回男女通用的名字
+fn init_given_name(gender: Option<Gender>) -> Vec<String> {
+    let names: &[&str] = match gender {
+        Some(Gender::Male) => &[
+            "子轩", "浩然", "宇航", "俊杰", "志强", "建国", "明轩", "皓轩", "子豪", "博文",
+            "天佑", "一鸣", "思远", "文博", "浩宇", "鹏飞", "志明", "国强", "建华", "晓东",
+            "家豪", "俊熙", "宇轩", "睿泽", "承泽", "明哲", "昊天", "振华", "立军", "海涛",
+        ],
+        Some(Gender::Female) => &[
+            "欣怡", "梓涵", "诗涵", "雨桐", "子涵", "可馨", "佳怡", "梦琪", "思彤", "雨欣",
+            "语嫣", "若曦", "婉清", "秀英", "桂英", "玉兰", "淑珍", "丽娟", "晓燕", "美玲",
+            "雪梅", "静怡", "紫萱", "佳琪", "一诺", "心怡", "慧敏", "雅婷", "诗琪", "艺涵",
+        ],
+        None => &[
+            "子墨", "一帆", "思源", "嘉乐", "安然", "雨辰", "晨曦", "若水", "清和", "子安",
+            "知秋", "文心", "天乐", "可言", "星辰",
+        ],
+    };
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn id_card_check_digit_matches_known_value() {
+        assert_eq!(id_card_check_digit("11010519491231002"), 'X');
+        assert_eq!(id_card_check_digit("44052418800101001"), '4');
+    }
+
+    #[test]
+    fn generated_id_cards_pass_check_digit() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let id = random_id_card(&Options::default(), &mut rng);
+            assert_eq!(id.chars().count(), 18, "{}", id);
+            assert_eq!(id.chars().last(), Some(id_card_check_digit(&id[..17])), "{}", id);
+        }
+    }
+
+    #[test]
+    fn uscc_check_chars_match_known_values() {
+        assert_eq!(org_code_check_char("D2143569"), 'X');
+        assert_eq!(uscc_check_char("91350100M000100Y4"), '3');
+    }
+
+    #[test]
+    fn generated_uscc_pass_check_chars() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let code = random_uscc(&Options::default(), &mut rng);
+            assert_eq!(code.len(), 18, "{}", code);
+            assert_eq!(code.chars().nth(16), Some(org_code_check_char(&code[8..16])), "{}", code);
+            assert_eq!(code.chars().last(), Some(uscc_check_char(&code[..17])), "{}", code);
+        }
+    }
+
+    #[test]
+    fn luhn_check_digit_matches_known_value() {
+        assert_eq!(luhn_check_digit("7992739871"), 3);
+        assert_eq!(luhn_check_digit("622202100112233445"), 8);
+    }
+
+    #[test]
+    fn generated_bank_cards_pass_luhn() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let card = random_bank_card(&Options::default(), &mut rng).value;
+            let (body, check) = card.split_at(card.len() - 1);
+            assert_eq!(check, luhn_check_digit(body).to_string(), "{}", card);
+        }
+    }
+
+    #[test]
+    fn number_stays_within_large_integer_ranges() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for (start, end) in [("1000000000000000000", "1000000000000000001"), ("9007199254740993", "9007199254740993")] {
+            let options = Options { range: Some((start.to_string(), end.to_string())), ..Options::default() };
+            for _ in 0..50 {
+                let value: i128 = random_number(&options, &mut rng).value.parse().unwrap();
+                assert!(start.parse::<i128>().unwrap() <= value && value <= end.parse::<i128>().unwrap(), "{}", value);
+            }
+        }
+    }
+
+    #[test]
+    fn number_pad_sets_width_without_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let tokens: Vec<String> = ["pad", "6"].iter().map(|t| t.to_string()).collect();
+        let options = Options::parse(RandomKind::Number, &tokens);
+        for _ in 0..20 {
+            assert_eq!(random_number(&options, &mut rng).value.len(), 6);
+        }
+    }
+
+    #[test]
+    fn decimal_rounds_inward_exactly() {
+        let decimal = Decimal::parse("0.3").unwrap();
+        assert_eq!((decimal.scaled(1, true), decimal.scaled(1, false)), (3, 3));
+        assert_eq!((decimal.scaled(0, true), decimal.scaled(0, false)), (1, 0));
+        let negative = Decimal::parse("-1.25").unwrap();
+        assert_eq!((negative.scaled(1, true), negative.scaled(1, false)), (-12, -13));
+        assert_eq!(Decimal::parse("1.5e3").unwrap().scaled(0, false), 1500);
+        assert!(Decimal::parse("abc").is_none());
+        assert!(!Decimal::parse("1e300").unwrap().in_i64());
+    }
+
+    #[test]
+    fn rmb_uppercase_matches_amount() {
+        assert_eq!(rmb_uppercase(20000500), "贰拾万零伍元整");
+        assert_eq!(rmb_uppercase(100000001), "壹佰万元零壹分");
+        assert_eq!(rmb_uppercase(0), "零元整");
+        assert_eq!(rmb_uppercase(1234567), "壹万贰仟叁佰肆拾伍元陆角柒分");
+        assert_eq!(rmb_uppercase(1_000_000_000_000_000), "壹拾万亿元整");
+    }
+
+    fn money(range: (&str, &str), words: &[&str], rng: &mut StdRng) -> GenerateRandom {
+        let tokens: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+        let options = Options { range: Some((range.0.to_string(), range.1.to_string())), ..Options::parse(RandomKind::Money, &tokens) };
+        random_money(&options, rng)
+    }
+
+    #[test]
+    fn money_supports_negative_amounts() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let value = money(("-100", "-50"), &["数字"], &mut rng);
+            let amount: f64 = value.value.parse().unwrap();
+            assert!((-100.0..=-50.0).contains(&amount), "{}", value.value);
+            let (label, uppercase) = &value.alternatives[1];
+            assert_eq!(label, "大写");
+            assert!(uppercase.starts_with('负'), "{}", uppercase);
+        }
+    }
+
+    #[test]
+    fn money_stays_within_rounded_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let value = money(("0.5", "0.9"), &["数字", "precision", "0"], &mut rng);
+            let amount: f64 = value.value.parse().unwrap();
+            assert!((0.5..=0.9).contains(&amount), "{}", value.value);
+        }
+    }
+
+    #[test]
+    fn money_rejects_amounts_over_the_cap() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let value = money(("10000000000000", "10000000000000"), &["大写"], &mut rng);
+        assert_eq!(value.value, "壹拾万亿元整");
+        assert_eq!(value.subtitle, None);
+        let value = money(("10000000000000.01", "10000000000000.01"), &[], &mut rng);
+        assert_eq!(value.subtitle.as_deref(), Some("金额超出 10 万亿, 使用 0.01..100000"));
+    }
+
+    #[test]
+    fn uuid_name_words_are_not_versions_or_formats() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for (query, expected) in [
+            ("v5 dns v1", "063835a7-b452-5487-bb72-813890675c72"),
+            ("v5 dns Upper", "bd557c20-2487-5db4-abbe-41ca47a0f40b"),
+            ("v5 dns 123", "37813542-0dca-5a8a-b2a2-b69c2d45583f"),
+        ] {
+            let tokens: Vec<String> = query.split_whitespace().map(|t| t.to_string()).collect();
+            let options = Options::parse(RandomKind::Uuid, &tokens);
+            assert_eq!(random_uuid(&options, &mut rng).value, expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn date_falls_back_when_format_has_time_fields() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for format in ["%H:%M", "%z", "%Y %S"] {
+            let options = Options { format: Some(format.to_string()), ..Options::default() };
+            let date = random_date(&options, &mut rng);
+            assert!(NaiveDate::parse_from_str(&date, "%Y-%m-%d").is_ok(), "{} -> {}", format, date);
+        }
+    }
+}
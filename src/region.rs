@@ -0,0 +1,729 @@
+/// 行政区划数据 省 -> 市 -> 区县, 代码取自 GB/T 2260, 附城市长途区号和区县邮政编码
+pub struct Province {
+    pub name: &'static str,
+    pub cities: &'static [City],
+}
+
+pub struct City {
+    pub name: &'static str,
+    /// 长途区号, 带开头的 0
+    pub area_code: &'static str,
+    /// 固定电话号码位数, 不含区号
+    pub subscriber_length: usize,
+    pub districts: &'static [District],
+}
+
+pub struct District {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub postal_code: &'static str,
+}
+
+const fn d(code: &'static str, name: &'static str, postal_code: &'static str) -> District {
+    District { code, name, postal_code }
+}
+
+pub static PROVINCES: &[Province] = &[
+    Province {
+        name: "北京市",
+        cities: &[
+            City {
+                name: "北京市",
+                area_code: "010",
+                subscriber_length: 8,
+                districts: &[
+                    d("110101", "东城区", "100010"),
+                    d("110102", "西城区", "100032"),
+                    d("110105", "朝阳区", "100020"),
+                    d("110106", "丰台区", "100071"),
+                    d("110107", "石景山区", "100043"),
+                    d("110108", "海淀区", "100080"),
+                    d("110109", "门头沟区", "102300"),
+                    d("110111", "房山区", "102488"),
+                    d("110112", "通州区", "101100"),
+                    d("110113", "顺义区", "101300"),
+                    d("110114", "昌平区", "102200"),
+                    d("110115", "大兴区", "102600"),
+                    d("110116", "怀柔区", "101400"),
+                    d("110117", "平谷区", "101200"),
+                    d("110118", "密云区", "101500"),
+                    d("110119", "延庆区", "102100"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "天津市",
+        cities: &[
+            City {
+                name: "天津市",
+                area_code: "022",
+                subscriber_length: 8,
+                districts: &[
+                    d("120101", "和平区", "300041"),
+                    d("120102", "河东区", "300171"),
+                    d("120103", "河西区", "300202"),
+                    d("120104", "南开区", "300100"),
+                    d("120105", "河北区", "300143"),
+                    d("120106", "红桥区", "300131"),
+                    d("120110", "东丽区", "300300"),
+                    d("120111", "西青区", "300380"),
+                    d("120112", "津南区", "300350"),
+                    d("120113", "北辰区", "300400"),
+                    d("120114", "武清区", "301700"),
+                    d("120116", "滨海新区", "300450"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "河北省",
+        cities: &[
+            City {
+                name: "石家庄市",
+                area_code: "0311",
+                subscriber_length: 8,
+                districts: &[
+                    d("130102", "长安区", "050011"),
+                    d("130104", "桥西区", "050091"),
+                    d("130105", "新华区", "050051"),
+                    d("130108", "裕华区", "050031"),
+                ],
+            },
+            City {
+                name: "唐山市",
+                area_code: "0315",
+                subscriber_length: 7,
+                districts: &[
+                    d("130202", "路南区", "063000"),
+                    d("130203", "路北区", "063000"),
+                    d("130204", "古冶区", "063100"),
+                    d("130205", "开平区", "063021"),
+                ],
+            },
+            City {
+                name: "保定市",
+                area_code: "0312",
+                subscriber_length: 7,
+                districts: &[
+                    d("130602", "竞秀区", "071052"),
+                    d("130606", "莲池区", "071000"),
+                    d("130607", "满城区", "072150"),
+                    d("130608", "清苑区", "071100"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "山西省",
+        cities: &[
+            City {
+                name: "太原市",
+                area_code: "0351",
+                subscriber_length: 7,
+                districts: &[
+                    d("140105", "小店区", "030032"),
+                    d("140106", "迎泽区", "030002"),
+                    d("140107", "杏花岭区", "030009"),
+                    d("140109", "万柏林区", "030024"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "内蒙古自治区",
+        cities: &[
+            City {
+                name: "呼和浩特市",
+                area_code: "0471",
+                subscriber_length: 7,
+                districts: &[
+                    d("150102", "新城区", "010050"),
+                    d("150103", "回民区", "010030"),
+                    d("150104", "玉泉区", "010020"),
+                    d("150105", "赛罕区", "010020"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "辽宁省",
+        cities: &[
+            City {
+                name: "沈阳市",
+                area_code: "024",
+                subscriber_length: 8,
+                districts: &[
+                    d("210102", "和平区", "110001"),
+                    d("210103", "沈河区", "110011"),
+                    d("210104", "大东区", "110041"),
+                    d("210105", "皇姑区", "110031"),
+                    d("210106", "铁西区", "110021"),
+                ],
+            },
+            City {
+                name: "大连市",
+                area_code: "0411",
+                subscriber_length: 8,
+                districts: &[
+                    d("210202", "中山区", "116001"),
+                    d("210203", "西岗区", "116011"),
+                    d("210204", "沙河口区", "116021"),
+                    d("210211", "甘井子区", "116033"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "吉林省",
+        cities: &[
+            City {
+                name: "长春市",
+                area_code: "0431",
+                subscriber_length: 8,
+                districts: &[
+                    d("220102", "南关区", "130022"),
+                    d("220103", "宽城区", "130051"),
+                    d("220104", "朝阳区", "130012"),
+                    d("220105", "二道区", "130031"),
+                    d("220106", "绿园区", "130062"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "黑龙江省",
+        cities: &[
+            City {
+                name: "哈尔滨市",
+                area_code: "0451",
+                subscriber_length: 8,
+                districts: &[
+                    d("230102", "道里区", "150010"),
+                    d("230103", "南岗区", "150006"),
+                    d("230104", "道外区", "150020"),
+                    d("230110", "香坊区", "150036"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "上海市",
+        cities: &[
+            City {
+                name: "上海市",
+                area_code: "021",
+                subscriber_length: 8,
+                districts: &[
+                    d("310101", "黄浦区", "200001"),
+                    d("310104", "徐汇区", "200030"),
+                    d("310105", "长宁区", "200050"),
+                    d("310106", "静安区", "200040"),
+                    d("310107", "普陀区", "200333"),
+                    d("310109", "虹口区", "200080"),
+                    d("310110", "杨浦区", "200082"),
+                    d("310112", "闵行区", "201100"),
+                    d("310113", "宝山区", "201900"),
+                    d("310114", "嘉定区", "201800"),
+                    d("310115", "浦东新区", "200120"),
+                    d("310116", "金山区", "200540"),
+                    d("310117", "松江区", "201600"),
+                    d("310118", "青浦区", "201700"),
+                    d("310120", "奉贤区", "201400"),
+                    d("310151", "崇明区", "202150"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "江苏省",
+        cities: &[
+            City {
+                name: "南京市",
+                area_code: "025",
+                subscriber_length: 8,
+                districts: &[
+                    d("320102", "玄武区", "210018"),
+                    d("320104", "秦淮区", "210001"),
+                    d("320105", "建邺区", "210004"),
+                    d("320106", "鼓楼区", "210009"),
+                    d("320111", "浦口区", "211800"),
+                    d("320113", "栖霞区", "210046"),
+                    d("320114", "雨花台区", "210012"),
+                    d("320115", "江宁区", "211100"),
+                ],
+            },
+            City {
+                name: "无锡市",
+                area_code: "0510",
+                subscriber_length: 8,
+                districts: &[
+                    d("320205", "锡山区", "214101"),
+                    d("320206", "惠山区", "214174"),
+                    d("320211", "滨湖区", "214123"),
+                    d("320213", "梁溪区", "214000"),
+                    d("320214", "新吴区", "214028"),
+                ],
+            },
+            City {
+                name: "苏州市",
+                area_code: "0512",
+                subscriber_length: 8,
+                districts: &[
+                    d("320505", "虎丘区", "215004"),
+                    d("320506", "吴中区", "215128"),
+                    d("320507", "相城区", "215131"),
+                    d("320508", "姑苏区", "215008"),
+                    d("320509", "吴江区", "215200"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "浙江省",
+        cities: &[
+            City {
+                name: "杭州市",
+                area_code: "0571",
+                subscriber_length: 8,
+                districts: &[
+                    d("330102", "上城区", "310002"),
+                    d("330105", "拱墅区", "310011"),
+                    d("330106", "西湖区", "310013"),
+                    d("330108", "滨江区", "310051"),
+                    d("330109", "萧山区", "311200"),
+                    d("330110", "余杭区", "311100"),
+                ],
+            },
+            City {
+                name: "宁波市",
+                area_code: "0574",
+                subscriber_length: 8,
+                districts: &[
+                    d("330203", "海曙区", "315000"),
+                    d("330205", "江北区", "315020"),
+                    d("330206", "北仑区", "315800"),
+                    d("330211", "镇海区", "315200"),
+                    d("330212", "鄞州区", "315100"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "安徽省",
+        cities: &[
+            City {
+                name: "合肥市",
+                area_code: "0551",
+                subscriber_length: 8,
+                districts: &[
+                    d("340102", "瑶海区", "230011"),
+                    d("340103", "庐阳区", "230001"),
+                    d("340104", "蜀山区", "230031"),
+                    d("340111", "包河区", "230041"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "福建省",
+        cities: &[
+            City {
+                name: "福州市",
+                area_code: "0591",
+                subscriber_length: 8,
+                districts: &[
+                    d("350102", "鼓楼区", "350001"),
+                    d("350103", "台江区", "350004"),
+                    d("350104", "仓山区", "350007"),
+                    d("350105", "马尾区", "350015"),
+                    d("350111", "晋安区", "350011"),
+                ],
+            },
+            City {
+                name: "厦门市",
+                area_code: "0592",
+                subscriber_length: 7,
+                districts: &[
+                    d("350203", "思明区", "361001"),
+                    d("350205", "海沧区", "361026"),
+                    d("350206", "湖里区", "361006"),
+                    d("350211", "集美区", "361021"),
+                    d("350212", "同安区", "361100"),
+                    d("350213", "翔安区", "361101"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "江西省",
+        cities: &[
+            City {
+                name: "南昌市",
+                area_code: "0791",
+                subscriber_length: 8,
+                districts: &[
+                    d("360102", "东湖区", "330006"),
+                    d("360103", "西湖区", "330009"),
+                    d("360104", "青云谱区", "330001"),
+                    d("360111", "青山湖区", "330029"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "山东省",
+        cities: &[
+            City {
+                name: "济南市",
+                area_code: "0531",
+                subscriber_length: 8,
+                districts: &[
+                    d("370102", "历下区", "250014"),
+                    d("370103", "市中区", "250001"),
+                    d("370104", "槐荫区", "250022"),
+                    d("370105", "天桥区", "250031"),
+                    d("370112", "历城区", "250100"),
+                ],
+            },
+            City {
+                name: "青岛市",
+                area_code: "0532",
+                subscriber_length: 8,
+                districts: &[
+                    d("370202", "市南区", "266001"),
+                    d("370203", "市北区", "266011"),
+                    d("370211", "黄岛区", "266500"),
+                    d("370212", "崂山区", "266100"),
+                    d("370213", "李沧区", "266041"),
+                    d("370214", "城阳区", "266109"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "河南省",
+        cities: &[
+            City {
+                name: "郑州市",
+                area_code: "0371",
+                subscriber_length: 8,
+                districts: &[
+                    d("410102", "中原区", "450007"),
+                    d("410103", "二七区", "450052"),
+                    d("410104", "管城回族区", "450000"),
+                    d("410105", "金水区", "450003"),
+                    d("410108", "惠济区", "450053"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "湖北省",
+        cities: &[
+            City {
+                name: "武汉市",
+                area_code: "027",
+                subscriber_length: 8,
+                districts: &[
+                    d("420102", "江岸区", "430014"),
+                    d("420103", "江汉区", "430021"),
+                    d("420104", "硚口区", "430033"),
+                    d("420105", "汉阳区", "430050"),
+                    d("420106", "武昌区", "430061"),
+                    d("420107", "青山区", "430080"),
+                    d("420111", "洪山区", "430070"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "湖南省",
+        cities: &[
+            City {
+                name: "长沙市",
+                area_code: "0731",
+                subscriber_length: 8,
+                districts: &[
+                    d("430102", "芙蓉区", "410011"),
+                    d("430103", "天心区", "410004"),
+                    d("430104", "岳麓区", "410006"),
+                    d("430105", "开福区", "410008"),
+                    d("430111", "雨花区", "410011"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "广东省",
+        cities: &[
+            City {
+                name: "广州市",
+                area_code: "020",
+                subscriber_length: 8,
+                districts: &[
+                    d("440103", "荔湾区", "510145"),
+                    d("440104", "越秀区", "510030"),
+                    d("440105", "海珠区", "510220"),
+                    d("440106", "天河区", "510630"),
+                    d("440111", "白云区", "510080"),
+                    d("440112", "黄埔区", "510700"),
+                    d("440113", "番禺区", "511400"),
+                    d("440114", "花都区", "510800"),
+                    d("440115", "南沙区", "511458"),
+                ],
+            },
+            City {
+                name: "深圳市",
+                area_code: "0755",
+                subscriber_length: 8,
+                districts: &[
+                    d("440303", "罗湖区", "518001"),
+                    d("440304", "福田区", "518033"),
+                    d("440305", "南山区", "518052"),
+                    d("440306", "宝安区", "518101"),
+                    d("440307", "龙岗区", "518172"),
+                    d("440308", "盐田区", "518081"),
+                    d("440309", "龙华区", "518109"),
+                    d("440310", "坪山区", "518118"),
+                    d("440311", "光明区", "518107"),
+                ],
+            },
+            City {
+                name: "佛山市",
+                area_code: "0757",
+                subscriber_length: 8,
+                districts: &[
+                    d("440604", "禅城区", "528000"),
+                    d("440605", "南海区", "528200"),
+                    d("440606", "顺德区", "528300"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "广西壮族自治区",
+        cities: &[
+            City {
+                name: "南宁市",
+                area_code: "0771",
+                subscriber_length: 7,
+                districts: &[
+                    d("450102", "兴宁区", "530012"),
+                    d("450103", "青秀区", "530022"),
+                    d("450105", "江南区", "530031"),
+                    d("450107", "西乡塘区", "530001"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "海南省",
+        cities: &[
+            City {
+                name: "海口市",
+                area_code: "0898",
+                subscriber_length: 8,
+                districts: &[
+                    d("460105", "秀英区", "570311"),
+                    d("460106", "龙华区", "570105"),
+                    d("460107", "琼山区", "571100"),
+                    d("460108", "美兰区", "570203"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "重庆市",
+        cities: &[
+            City {
+                name: "重庆市",
+                area_code: "023",
+                subscriber_length: 8,
+                districts: &[
+                    d("500101", "万州区", "404000"),
+                    d("500103", "渝中区", "400010"),
+                    d("500104", "大渡口区", "400080"),
+                    d("500105", "江北区", "400020"),
+                    d("500106", "沙坪坝区", "400030"),
+                    d("500107", "九龙坡区", "400050"),
+                    d("500108", "南岸区", "400060"),
+                    d("500109", "北碚区", "400700"),
+                    d("500112", "渝北区", "401120"),
+                    d("500113", "巴南区", "401320"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "四川省",
+        cities: &[
+            City {
+                name: "成都市",
+                area_code: "028",
+                subscriber_length: 8,
+                districts: &[
+                    d("510104", "锦江区", "610021"),
+                    d("510105", "青羊区", "610031"),
+                    d("510106", "金牛区", "610036"),
+                    d("510107", "武侯区", "610041"),
+                    d("510108", "成华区", "610066"),
+                    d("510112", "龙泉驿区", "610100"),
+                    d("510114", "新都区", "610500"),
+                    d("510116", "双流区", "610200"),
+                    d("510117", "郫都区", "611730"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "贵州省",
+        cities: &[
+            City {
+                name: "贵阳市",
+                area_code: "0851",
+                subscriber_length: 8,
+                districts: &[
+                    d("520102", "南明区", "550001"),
+                    d("520103", "云岩区", "550001"),
+                    d("520111", "花溪区", "550025"),
+                    d("520112", "乌当区", "550018"),
+                    d("520113", "白云区", "550014"),
+                    d("520115", "观山湖区", "550081"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "云南省",
+        cities: &[
+            City {
+                name: "昆明市",
+                area_code: "0871",
+                subscriber_length: 8,
+                districts: &[
+                    d("530102", "五华区", "650032"),
+                    d("530103", "盘龙区", "650051"),
+                    d("530111", "官渡区", "650200"),
+                    d("530112", "西山区", "650118"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "西藏自治区",
+        cities: &[
+            City {
+                name: "拉萨市",
+                area_code: "0891",
+                subscriber_length: 7,
+                districts: &[
+                    d("540102", "城关区", "850000"),
+                    d("540103", "堆龙德庆区", "851400"),
+                    d("540104", "达孜区", "850100"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "陕西省",
+        cities: &[
+            City {
+                name: "西安市",
+                area_code: "029",
+                subscriber_length: 8,
+                districts: &[
+                    d("610102", "新城区", "710004"),
+                    d("610103", "碑林区", "710001"),
+                    d("610104", "莲湖区", "710003"),
+                    d("610111", "灞桥区", "710038"),
+                    d("610112", "未央区", "710014"),
+                    d("610113", "雁塔区", "710061"),
+                    d("610116", "长安区", "710100"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "甘肃省",
+        cities: &[
+            City {
+                name: "兰州市",
+                area_code: "0931",
+                subscriber_length: 7,
+                districts: &[
+                    d("620102", "城关区", "730030"),
+                    d("620103", "七里河区", "730050"),
+                    d("620104", "西固区", "730060"),
+                    d("620105", "安宁区", "730070"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "青海省",
+        cities: &[
+            City {
+                name: "西宁市",
+                area_code: "0971",
+                subscriber_length: 7,
+                districts: &[
+                    d("630102", "城东区", "810007"),
+                    d("630103", "城中区", "810000"),
+                    d("630104", "城西区", "810001"),
+                    d("630105", "城北区", "810003"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "宁夏回族自治区",
+        cities: &[
+            City {
+                name: "银川市",
+                area_code: "0951",
+                subscriber_length: 7,
+                districts: &[
+                    d("640104", "兴庆区", "750001"),
+                    d("640105", "西夏区", "750021"),
+                    d("640106", "金凤区", "750002"),
+                ],
+            },
+        ],
+    },
+    Province {
+        name: "新疆维吾尔自治区",
+        cities: &[
+            City {
+                name: "乌鲁木齐市",
+                area_code: "0991",
+                subscriber_length: 7,
+                districts: &[
+                    d("650102", "天山区", "830002"),
+                    d("650103", "沙依巴克区", "830000"),
+                    d("650104", "新市区", "830011"),
+                    d("650105", "水磨沟区", "830017"),
+                ],
+            },
+        ],
+    },
+];
+
+/// 展开所有区县, 便于等概率随机选取
+/// keyword 非空时只保留省、市或区县名称中包含该关键字的区县, 例如 `广东`、`深圳`
+pub fn find_districts(keyword: &str) -> Vec<(&'static Province, &'static City, &'static District)> {
+    let mut districts = Vec::new();
+    for province in PROVINCES {
+        for city in province.cities {
+            for district in city.districts {
+                if keyword.is_empty()
+                    || province.name.contains(keyword)
+                    || city.name.contains(keyword)
+                    || district.name.contains(keyword) {
+                    districts.push((province, city, district));
+                }
+            }
+        }
+    }
+    districts
+}
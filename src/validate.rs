@@ -0,0 +1,125 @@
+use std::fmt;
+use crate::random::init_phone_prefix;
+
+/// 校验通过的手机号
+#[derive(Debug, PartialEq)]
+pub struct Phone {
+    /// 去掉国家码和分隔符后的 11 位号码
+    pub number: String,
+    pub prefix: String,
+    pub carrier: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PhoneError {
+    /// 去掉分隔符后含有数字以外的字符
+    NotDigits,
+    /// 号码位数不是 11 位
+    Length(usize),
+    /// 号段未分配给任何运营商
+    Segment(String),
+}
+
+impl fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhoneError::NotDigits => write!(f, "只能包含数字"),
+            PhoneError::Length(length) => write!(f, "应为 11 位, 实际 {} 位", length),
+            PhoneError::Segment(segment) => write!(f, "号段 {} 不属于任何运营商", segment),
+        }
+    }
+}
+
+/// 校验手机号的位数和号段, 并按最长匹配的号段查找运营商
+/// 允许空格、横线、括号分隔以及 `+86`、`0086` 国家码, 与 `random_phone` 的各种格式对应
+pub fn validate_phone(input: &str) -> Result<Phone, PhoneError> {
+    let digits: String = input.chars()
+        .filter(|c| !c.is_whitespace() && !['-', '(', ')', '+'].contains(c))
+        .collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(PhoneError::NotDigits);
+    }
+    let number = match digits.len() {
+        13 if digits.starts_with("86") => &digits[2..],
+        15 if digits.starts_with("0086") => &digits[4..],
+        _ => &digits,
+    };
+    if number.len() != 11 {
+        return Err(PhoneError::Length(number.len()));
+    }
+    init_phone_prefix().into_iter()
+        .filter(|(_, _, prefix)| number.starts_with(prefix.as_str()))
+        .max_by_key(|(_, _, prefix)| prefix.len())
+        .map(|(_, carrier, prefix)| Phone { number: number.to_string(), prefix, carrier })
+        .ok_or_else(|| PhoneError::Segment(number[..4].to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::Options;
+    use crate::random::{random_value, RandomKind};
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn accepts_carrier_segments() {
+        assert_eq!(validate_phone("13812345678").unwrap().carrier, "中国移动");
+        assert_eq!(validate_phone("13012345678").unwrap().carrier, "中国联通");
+        assert_eq!(validate_phone("19912345678").unwrap().carrier, "中国电信");
+        assert_eq!(validate_phone("19212345678").unwrap().carrier, "中国广电");
+        assert_eq!(validate_phone("17012345678").unwrap().carrier, "虚拟运营商");
+        assert_eq!(validate_phone("13412345678").unwrap().prefix, "1341");
+    }
+
+    #[test]
+    fn accepts_formatted_numbers() {
+        for input in ["+86 138 1234 5678", "+8613812345678", "138-1234-5678", "(+86) 13812345678", "0086 13812345678"] {
+            assert_eq!(validate_phone(input).unwrap().number, "13812345678", "{}", input);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        // 旧的 13x 号段为 4 位前缀加 8 位, 生成 12 位号码
+        assert_eq!(validate_phone("133012345678"), Err(PhoneError::Length(12)));
+        assert_eq!(validate_phone("1381234567"), Err(PhoneError::Length(10)));
+    }
+
+    #[test]
+    fn rejects_unassigned_segments() {
+        assert_eq!(validate_phone("12012345678"), Err(PhoneError::Segment("1201".to_string())));
+        assert_eq!(validate_phone("13491234567"), Err(PhoneError::Segment("1349".to_string())));
+        assert_eq!(validate_phone("1381234567a"), Err(PhoneError::NotDigits));
+    }
+
+    fn phone_options(words: &[&str]) -> Options {
+        let tokens: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+        Options::parse(RandomKind::Phone, &tokens)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_phones_are_valid(seed: u64) {
+            let value = random_value(RandomKind::Phone, &phone_options(&[]), &mut StdRng::seed_from_u64(seed));
+            let phone = validate_phone(&value.value).unwrap();
+            prop_assert_eq!(value.subtitle, Some(format!("phone {}", phone.carrier)));
+        }
+
+        #[test]
+        fn generated_formats_are_valid(seed: u64, format in prop::sample::select(vec!["空格", "+86", "横线", "括号"])) {
+            let value = random_value(RandomKind::Phone, &phone_options(&[format]), &mut StdRng::seed_from_u64(seed));
+            prop_assert!(validate_phone(&value.value).is_ok(), "{}", value.value);
+            for (_, alternative) in &value.alternatives {
+                prop_assert!(validate_phone(alternative).is_ok(), "{}", alternative);
+            }
+        }
+
+        #[test]
+        fn carrier_filter_is_respected(seed: u64, carrier in prop::sample::select(vec![("cm", "中国移动"), ("cu", "中国联通"), ("ct", "中国电信"), ("cbn", "中国广电"), ("mvno", "虚拟运营商")])) {
+            let value = random_value(RandomKind::Phone, &phone_options(&[carrier.0]), &mut StdRng::seed_from_u64(seed));
+            prop_assert_eq!(validate_phone(&value.value).unwrap().carrier, carrier.1);
+        }
+    }
+}
@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
-use random::RandomKind;
-use crate::{GenerateRandom, random};
+use crate::GenerateRandom;
+use crate::random::RandomKind;
+use crate::validate;
 
 static AUTHOR_WEBSITE: &str = "https://hongdenglv.com";
 
+/// 备选结果依次绑定的修饰键
+static MODIFIER_KEYS: [&str; 5] = ["alt", "cmd", "ctrl", "shift", "fn"];
+
 #[derive(Serialize, Deserialize)]
 pub struct Workflows {
     pub items: Vec<Workflow>,
@@ -20,8 +25,21 @@ pub struct Workflow {
     subtitle: String,
     title: String,
     uid: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    mods: BTreeMap<String, Modifier>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    autocomplete: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    valid: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Modifier {
+    arg: String,
+    subtitle: String,
 }
 #[derive(Serialize, Deserialize)]
+#[allow(dead_code)]
 pub struct Text {
     copy: String,
     largetype: String,
@@ -31,7 +49,14 @@ impl Workflows {
     pub fn new(generate_value: Vec<GenerateRandom>) -> Workflows {
         let mut items = Vec::new();
         for value in generate_value {
-            let workflow = Workflow::new(value.value.clone(), value.kind);
+            let subtitle = value.subtitle.unwrap_or_else(|| value.kind.get_name());
+            let mut workflow = Workflow::new(value.value, subtitle);
+            for (key, (label, arg)) in MODIFIER_KEYS.iter().zip(value.alternatives) {
+                workflow.mods.insert(key.to_string(), Modifier {
+                    subtitle: format!("{} {}", label, arg),
+                    arg,
+                });
+            }
             items.push(workflow);
         }
         Workflows {
@@ -39,19 +64,85 @@ impl Workflows {
         }
     }
 
+    /// 列出候选类型, 选中后补全为 `kind rest`
+    pub fn choose(kinds: Vec<RandomKind>, rest: &str) -> Workflows {
+        Workflows {
+            items: kinds.into_iter().map(|kind| Workflow::kind(kind, rest)).collect(),
+        }
+    }
+
+    /// 提示没有该类型, 并列出拼写相近的类型
+    pub fn not_found(name: &str, suggestions: Vec<RandomKind>, rest: &str) -> Workflows {
+        let names: Vec<String> = suggestions.iter().map(|k| k.get_name()).collect();
+        let subtitle = if names.is_empty() {
+            "输入为空可查看全部类型".to_string()
+        } else {
+            format!("你是不是想输入 {}", names.join("、"))
+        };
+        let mut header = Workflow::new(String::new(), subtitle);
+        header.title = format!("没有类型 {}", name);
+        header.autocomplete = Some(String::new());
+        header.valid = Some(false);
+        let mut items = vec![header];
+        items.extend(suggestions.into_iter().map(|kind| Workflow::kind(kind, rest)));
+        Workflows {
+            items,
+        }
+    }
+
+    /// 校验结果, 合法时回车复制规范化后的值
+    pub fn validate(name: &str, kind: Option<RandomKind>, value: &str) -> Workflows {
+        let result = match kind {
+            Some(RandomKind::Phone) => validate::validate_phone(value)
+                .map(|phone| (phone.number, format!("{} {} 号段", phone.carrier, phone.prefix)))
+                .map_err(|e| e.to_string()),
+            Some(kind) => Err(format!("暂不支持校验 {}", kind.get_name())),
+            None => Err(format!("没有类型 {}, 例如 validate phone 13812345678", name)),
+        };
+        let workflow = match result {
+            Ok((arg, subtitle)) => {
+                let mut workflow = Workflow::new(arg, subtitle);
+                workflow.title = format!("{} 有效", workflow.title);
+                workflow
+            }
+            Err(subtitle) => {
+                let mut workflow = Workflow::new(value.to_string(), subtitle);
+                workflow.title = format!("{} 无效", value);
+                workflow.valid = Some(false);
+                workflow
+            }
+        };
+        Workflows {
+            items: vec![workflow],
+        }
+    }
+
     pub fn to_json(&self) -> String {
         serde_json::to_string(&self).unwrap()
     }
 }
 
 impl Workflow {
-    pub fn new(arg: String, kind: RandomKind) -> Workflow {
+    pub fn new(arg: String, subtitle: String) -> Workflow {
         Workflow {
             arg: arg.clone(),
             quicklookurl: AUTHOR_WEBSITE.to_string(),
-            subtitle: kind.get_name(),
+            subtitle,
             title: arg.clone(),
             uid: String::from(Uuid::new_v4()),
+            mods: BTreeMap::new(),
+            autocomplete: None,
+            valid: None,
         }
     }
+
+    /// 类型候选项, 不可直接执行, 回车或 tab 补全类型名称
+    fn kind(kind: RandomKind, rest: &str) -> Workflow {
+        let subtitle = format!("{}  别名 {}", kind.get_description(), kind.get_aliases()[1..].join(" "));
+        let mut workflow = Workflow::new(kind.get_name(), subtitle);
+        let completion = if rest.is_empty() { kind.get_name() } else { format!("{} {}", kind.get_name(), rest) };
+        workflow.autocomplete = Some(format!("{} ", completion));
+        workflow.valid = Some(false);
+        workflow
+    }
 }
\ No newline at end of file